@@ -0,0 +1,34 @@
+use actix_web::HttpResponse;
+
+/// Converts an `anyhow::Error` into an `Internal Server Error` response,
+/// the body is the error chain in debug builds and a generic message
+/// in release builds, so the internals are not exposed.
+pub fn anyhow_into_500(error: anyhow::Error) -> HttpResponse {
+    let body = if cfg!(debug_assertions) {
+        format!("{:#}", error)
+    } else {
+        "internal error".to_string()
+    };
+
+    HttpResponse::InternalServerError().body(body)
+}
+
+/// This is an `anyhow::Error` that can be used as the error of a route,
+/// such as `HttpResult<AnyhowError>`, so `?` works on `anyhow::Result`.
+///
+/// It's a wrapper because neither `anyhow::Error` nor `HttpResponse`
+/// belong to this crate, which can't implement `From` between them.
+#[derive(Debug)]
+pub struct AnyhowError(pub anyhow::Error);
+
+impl From<anyhow::Error> for AnyhowError {
+    fn from(error: anyhow::Error) -> Self {
+        Self(error)
+    }
+}
+
+impl From<AnyhowError> for HttpResponse {
+    fn from(error: AnyhowError) -> Self {
+        anyhow_into_500(error.0)
+    }
+}
@@ -0,0 +1,81 @@
+use std::fmt::Display;
+
+use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
+
+/// This trait adds helpers to convert the errors of a result
+/// in route handlers, it's implemented for every `Result`.
+// the responses are returned as errors on purpose, routes return them as they are.
+#[allow(clippy::result_large_err)]
+pub trait HttpResultExt<T, E> {
+    /// Converts the error into another error type with `Into`.
+    fn map_err_into<F>(self) -> Result<T, F>
+    where
+        E: Into<F>;
+
+    /// Converts the error into its response and replaces the status
+    /// of that response, the body and headers are kept.
+    fn context_status(self, status: StatusCode) -> Result<T, HttpResponse>
+    where
+        E: Into<HttpResponse>;
+
+    /// Converts the error into an `Internal Server Error` response
+    /// with the formatted error as body.
+    fn or_500(self) -> Result<T, HttpResponse>
+    where
+        E: Display;
+}
+
+#[allow(clippy::result_large_err)]
+impl<T, E> HttpResultExt<T, E> for Result<T, E> {
+    fn map_err_into<F>(self) -> Result<T, F>
+    where
+        E: Into<F>,
+    {
+        self.map_err(Into::into)
+    }
+
+    fn context_status(self, status: StatusCode) -> Result<T, HttpResponse>
+    where
+        E: Into<HttpResponse>,
+    {
+        self.map_err(|error| {
+            let mut response = error.into();
+            *response.status_mut() = status;
+            response
+        })
+    }
+
+    fn or_500(self) -> Result<T, HttpResponse>
+    where
+        E: Display,
+    {
+        self.map_err(|error| HttpResponse::InternalServerError().body(format!("{:#}", error)))
+    }
+}
+
+/// This trait converts results from other libraries into the
+/// result of a route, converting the error with `Into`.
+pub trait IntoHttpResult<T, E> {
+    /// Converts the result, the error is converted with `Into`.
+    fn into_http_result(self) -> Result<T, E>;
+}
+
+impl<T, E, F: Into<E>> IntoHttpResult<T, E> for Result<T, F> {
+    fn into_http_result(self) -> Result<T, E> {
+        self.map_err(Into::into)
+    }
+}
+
+/// This trait converts options into the result of a route,
+/// `None` becomes the given error.
+pub trait IntoHttpResultOr<T, E> {
+    /// Converts the option, `None` becomes `none_err`.
+    fn into_http_result_or(self, none_err: E) -> Result<T, E>;
+}
+
+impl<T, E> IntoHttpResultOr<T, E> for Option<T> {
+    fn into_http_result_or(self, none_err: E) -> Result<T, E> {
+        self.ok_or(none_err)
+    }
+}
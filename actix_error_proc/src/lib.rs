@@ -1,8 +1,29 @@
+use std::convert::Infallible;
+
 use actix_web::HttpResponse;
 
-pub use actix_error_proc_macros::{proof_route, ActixError};
+#[cfg(feature = "anyhow")]
+mod anyhow_error;
+pub mod ext;
+mod macros;
+mod negotiation;
+#[cfg(feature = "openapi")]
+pub mod openapi;
+mod retry;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use actix_error_proc_macros::{error_chain, proof_route, proof_routes, unify_errors, ActixError};
+#[doc(hidden)]
+pub use actix_error_proc_macros::__unify_errors;
+#[cfg(feature = "anyhow")]
+pub use anyhow_error::{anyhow_into_500, AnyhowError};
+pub use ext::{IntoHttpResult, IntoHttpResultOr};
+pub use negotiation::ResponseFormat;
+pub use retry::RetryBudget;
 #[cfg(feature = "thiserror")]
 pub use thiserror::Error;
 /// This is a type alias that you can use as http
-/// route handler result, it binds to `Result<HttpResponse, E>`.
-pub type HttpResult<E> = Result<HttpResponse, E>;
+/// route handler result, it binds to `Result<HttpResponse, E>`,
+/// routes that can't fail can use `HttpResult` alone.
+pub type HttpResult<E = Infallible> = Result<HttpResponse, E>;
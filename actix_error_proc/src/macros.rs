@@ -0,0 +1,86 @@
+/// Returns early from a route handler, `bail_http!(error)` returns
+/// `Err(error)` and `bail_http!(status, "message", args...)` returns an
+/// `Ok` response with that status and the formatted message as body,
+/// which skips the error type of the route.
+#[macro_export]
+macro_rules! bail_http {
+    ($error:expr $(,)?) => {
+        return ::core::result::Result::Err($error)
+    };
+
+    ($status:expr, $($message:tt)+) => {
+        return ::core::result::Result::Ok(
+            ::actix_web::HttpResponse::build($status).body(::std::format!($($message)+))
+        )
+    };
+}
+
+/// Returns early from a route handler when the condition is false,
+/// the arguments after the condition are the ones of `bail_http!`.
+#[macro_export]
+macro_rules! ensure_http {
+    ($condition:expr, $($bail:tt)+) => {
+        if !$condition {
+            $crate::bail_http!($($bail)+);
+        }
+    };
+}
+
+/// Builds an `Ok` response with the value serialized as JSON.
+#[macro_export]
+macro_rules! ok_json {
+    ($value:expr $(,)?) => {
+        ::core::result::Result::Ok(::actix_web::HttpResponse::Ok().json($value))
+    };
+}
+
+/// Builds an `Err` response with the status and the formatted message as body,
+/// for routes returning `Result<HttpResponse, HttpResponse>`.
+#[macro_export]
+macro_rules! err_body {
+    ($status:expr, $($message:tt)+) => {
+        ::core::result::Result::Err(
+            ::actix_web::HttpResponse::build($status).body(::std::format!($($message)+))
+        )
+    };
+}
+
+/// Records a route for `openapi::routes`, used by `proof_route`.
+#[cfg(feature = "openapi")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __openapi_route {
+    ($($field:ident: $value:expr),* $(,)?) => {
+        $crate::openapi::inventory::submit! {
+            $crate::openapi::RouteDoc { $($field: $value),* }
+        }
+    };
+}
+
+/// The `openapi` feature is disabled, so the route is not recorded.
+#[cfg(not(feature = "openapi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __openapi_route {
+    ($($tokens:tt)*) => {};
+}
+
+/// Records a route for `openapi::export_schemas`, used by `proof_route`.
+#[cfg(feature = "openapi")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __schema_route {
+    ($($field:ident: $value:expr),* $(,)?) => {
+        $crate::openapi::inventory::submit! {
+            $crate::openapi::RouteSchema { $($field: $value),* }
+        }
+    };
+}
+
+/// The `openapi` feature is disabled, so the schema is not recorded.
+#[cfg(not(feature = "openapi"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __schema_route {
+    ($($tokens:tt)*) => {};
+}
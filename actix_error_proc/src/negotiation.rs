@@ -0,0 +1,42 @@
+use actix_web::http::header::{Accept, Header, Quality};
+use actix_web::HttpRequest;
+
+/// This is the response format picked from the `Accept` header
+/// of a request by a `proof_route` with `content_negotiation` set,
+/// it can be read in the route with `web::ReqData<ResponseFormat>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResponseFormat {
+    Json,
+    Xml,
+}
+
+impl ResponseFormat {
+    /// Picks the format the client prefers the most from the `Accept` header,
+    /// if the header is missing or malformed `Json` is assumed, and if none
+    /// of the accepted types is supported `None` is returned, types with
+    /// `q=0` are not acceptable, so they are never picked.
+    pub fn negotiate(req: &HttpRequest) -> Option<Self> {
+        let Ok(accept) = Accept::parse(req) else {
+            return Some(Self::Json);
+        };
+
+        if accept.is_empty() {
+            return Some(Self::Json);
+        }
+
+        let acceptable = accept
+            .iter()
+            .filter(|item| item.quality != Quality::ZERO)
+            .cloned()
+            .collect();
+
+        Accept(acceptable)
+            .ranked()
+            .iter()
+            .find_map(|mime| match mime.essence_str() {
+                "*/*" | "application/*" | "application/json" => Some(Self::Json),
+                "application/xml" | "text/xml" => Some(Self::Xml),
+                _ => None,
+            })
+    }
+}
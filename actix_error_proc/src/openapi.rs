@@ -0,0 +1,105 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+#[doc(hidden)]
+pub use inventory;
+
+/// This is a route declared with the `openapi_tag` or `openapi_summary`
+/// options of `proof_route`, one is recorded for every method of the route.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteDoc {
+    /// The HTTP method of the route in uppercase, such as `GET`.
+    pub method: &'static str,
+    /// The path of the route as declared, such as `/users/{id}`.
+    pub path: &'static str,
+    /// The tag the route is grouped under, if any.
+    pub tag: Option<&'static str>,
+    /// The short description of the route, if any.
+    pub summary: Option<&'static str>,
+}
+
+inventory::collect!(RouteDoc);
+
+/// Iterates over the documented routes of every crate linked in the
+/// binary, meant to build an OpenAPI document out of them, the order
+/// of the routes is not specified.
+pub fn routes() -> impl Iterator<Item = &'static RouteDoc> {
+    inventory::iter::<RouteDoc>.into_iter()
+}
+
+/// This is a route declared with the `export_schema` option of `proof_route`,
+/// which `export_schemas` writes to its file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteSchema {
+    /// The absolute path of the file the schema is written to.
+    pub file: &'static str,
+    /// The name of the route handler.
+    pub name: &'static str,
+    /// The HTTP methods of the route in uppercase, in declaration order.
+    pub methods: &'static [&'static str],
+    /// The path of the route as declared, such as `/users/{id}`.
+    pub path: &'static str,
+    /// The `response_schema` of the route, if any.
+    pub response_schema: Option<&'static str>,
+    /// The error type of the route, if it can fail.
+    pub error: Option<&'static str>,
+    /// The variants of the error type, if it's described.
+    pub variants: Option<&'static [&'static str]>,
+}
+
+inventory::collect!(RouteSchema);
+
+/// Iterates over the routes with an exported schema of every crate
+/// linked in the binary, the order of the routes is not specified.
+pub fn schemas() -> impl Iterator<Item = &'static RouteSchema> {
+    inventory::iter::<RouteSchema>.into_iter()
+}
+
+/// Writes the schema of every route declared with `export_schema` to its file,
+/// the routes of a file are sorted by name and get an entry per method, files
+/// already up to date are left untouched.
+pub fn export_schemas() -> std::io::Result<()> {
+    let mut files = BTreeMap::<&str, Vec<&RouteSchema>>::new();
+
+    for schema in schemas() {
+        files.entry(schema.file).or_default().push(schema);
+    }
+
+    for (file, mut routes) in files {
+        routes.sort_by_key(|route| (route.name, route.path));
+
+        let entries = routes
+            .iter()
+            .flat_map(|route| {
+                route.methods.iter().map(|method| {
+                    json!({
+                        "name": route.name,
+                        "method": method,
+                        "path": route.path,
+                        "response_schema": route.response_schema,
+                        "error": route.error,
+                        "variants": route.variants,
+                    })
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let path = Path::new(file);
+        let schema = format!("{:#}\n", Value::Array(entries));
+
+        if fs::read_to_string(path).is_ok_and(|current| current == schema) {
+            continue;
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, schema)?;
+    }
+
+    Ok(())
+}
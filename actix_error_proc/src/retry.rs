@@ -0,0 +1,10 @@
+use std::net::IpAddr;
+
+/// This trait is implemented by the types used in the `retry_budget`
+/// option of `proof_route`, which must be registered as `web::Data`
+/// in the app.
+pub trait RetryBudget {
+    /// Consumes one retry from the budget of a client, returning
+    /// `false` once the budget of that client is exhausted.
+    fn consume(&self, client: IpAddr) -> bool;
+}
@@ -0,0 +1,134 @@
+use std::sync::mpsc::channel;
+use std::thread::{spawn, JoinHandle};
+
+use actix_web::dev::{HttpServiceFactory, ServerHandle};
+use actix_web::rt::System;
+use actix_web::{App, HttpServer};
+use reqwest::{Client, Method, RequestBuilder};
+
+pub use crate::{assert_body, assert_status};
+
+/// This is a server bound to a random local port which serves
+/// the given routes, meant to test them with real requests.
+///
+/// The server runs in its own thread and must be stopped with
+/// `stop` at the end of the test.
+pub struct TestApp {
+    address: String,
+    handle: ServerHandle,
+    client: Client,
+    thread: JoinHandle<()>,
+}
+
+impl TestApp {
+    /// Starts a server with the service returned by the factory, which is
+    /// called once per worker, such as `TestApp::with_service(|| some_route)`.
+    pub fn with_service<F, S>(factory: F) -> Self
+    where
+        F: Fn() -> S + Send + Clone + 'static,
+        S: HttpServiceFactory + 'static,
+    {
+        let (tx_handle, rx_handle) = channel();
+        let (tx_address, rx_address) = channel();
+
+        let thread = spawn(move || {
+            let system = System::new();
+            let server = HttpServer::new(move || App::new().service(factory()))
+                .bind(("127.0.0.1", 0))
+                .expect("Error while binding the test server.");
+
+            tx_address
+                .send(format!("http://{}", server.addrs()[0]))
+                .unwrap();
+
+            let server = server.run();
+
+            tx_handle.send(server.handle()).unwrap();
+            system.block_on(server).unwrap();
+        });
+
+        Self {
+            address: rx_address.recv().unwrap(),
+            handle: rx_handle.recv().unwrap(),
+            client: Client::new(),
+            thread,
+        }
+    }
+
+    /// The address of the server, such as `http://127.0.0.1:8080`.
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// The handle of the server.
+    pub fn handle(&self) -> &ServerHandle {
+        &self.handle
+    }
+
+    /// The client used to make the requests.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// The full url of a path in the server, the path must start with `/`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{}", self.address, path)
+    }
+
+    /// Starts a request to a path in the server.
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.client.request(method, self.url(path))
+    }
+
+    /// Starts a `GET` request to a path in the server.
+    pub fn get(&self, path: &str) -> RequestBuilder {
+        self.request(Method::GET, path)
+    }
+
+    /// Starts a `POST` request to a path in the server.
+    pub fn post(&self, path: &str) -> RequestBuilder {
+        self.request(Method::POST, path)
+    }
+
+    /// Stops the server gracefully and waits for its thread to end.
+    pub async fn stop(self) {
+        self.handle.stop(true).await;
+        self.thread.join().unwrap();
+    }
+}
+
+/// Asserts the status of a `reqwest::Response`, the status can be
+/// a `StatusCode` of `reqwest` or `actix_web`.
+#[macro_export]
+macro_rules! assert_status {
+    ($response:expr, $status:expr $(,)?) => {{
+        let actual = $response.status().as_u16();
+        let expected = $status.as_u16();
+
+        ::core::assert!(
+            actual == expected,
+            "Expected the response status to be {} but it was {}.",
+            expected,
+            actual
+        );
+    }};
+}
+
+/// Asserts the body of a `reqwest::Response` as text, this consumes
+/// the response and awaits the body, so it must be used in async code.
+#[macro_export]
+macro_rules! assert_body {
+    ($response:expr, $body:expr $(,)?) => {{
+        let actual = $response
+            .text()
+            .await
+            .expect("Error while reading response body.");
+
+        ::core::assert!(
+            actual == $body,
+            "Expected the response body to be {:?} but it was {:?}.",
+            $body,
+            actual
+        );
+    }};
+}
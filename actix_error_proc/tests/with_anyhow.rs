@@ -0,0 +1,62 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{anyhow_into_500, proof_route, AnyhowError, HttpResult};
+use actix_web::http::StatusCode as ActixStatusCode;
+use actix_web::body::to_bytes;
+use actix_web::HttpResponse;
+use anyhow::{anyhow, Context};
+use reqwest::StatusCode;
+use tokio::test;
+
+fn load_config() -> anyhow::Result<String> {
+    Err(anyhow!("the file is missing")).context("The config couldn't be loaded")
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<AnyhowError> {
+    let config = load_config()?;
+
+    Ok(HttpResponse::Ok().body(config))
+}
+
+#[test]
+async fn should_convert_anyhow_errors() {
+    let response = anyhow_into_500(anyhow!("something failed"));
+
+    assert_eq!(response.status(), ActixStatusCode::INTERNAL_SERVER_ERROR);
+
+    let body = to_bytes(response.into_body())
+        .await
+        .expect("Error while reading response body.");
+
+    if cfg!(debug_assertions) {
+        assert_eq!(body, "something failed");
+    } else {
+        assert_eq!(body, "internal error");
+    }
+}
+
+#[test]
+async fn should_respond_with_error_chain() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    if cfg!(debug_assertions) {
+        assert_eq!(text, "The config couldn't be loaded: the file is missing");
+    } else {
+        assert_eq!(text, "internal error");
+    }
+
+    app.stop().await;
+}
@@ -0,0 +1,74 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{bail_http, proof_route, ActixError, HttpResult};
+use actix_web::{http::StatusCode as ActixStatusCode, web::Path, HttpResponse};
+use reqwest::StatusCode;
+use thiserror::Error;
+use tokio::test;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("not found")]
+    #[http_status(NotFound)]
+    NotFound
+}
+
+#[proof_route(get("/{id}"))]
+async fn test_route(id: Path<u32>) -> HttpResult<TestError> {
+    match id.into_inner() {
+        0 => bail_http!(TestError::NotFound),
+        1 => bail_http!(ActixStatusCode::GONE, "item {} was removed", 1),
+        _ => Ok(HttpResponse::Ok().body("found")),
+    }
+}
+
+#[test]
+async fn should_bail_with_error() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/0")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_bail_with_response() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/1")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::GONE);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "item 1 was removed");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_not_bail() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/2")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    app.stop().await;
+}
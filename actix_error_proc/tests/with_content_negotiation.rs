@@ -0,0 +1,120 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{proof_route, ActixError, HttpResult, ResponseFormat};
+use actix_web::{web::ReqData, HttpResponse};
+use reqwest::{header::HeaderValue, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[proof_route(get("/"), content_negotiation)]
+async fn test_route(format: ReqData<ResponseFormat>) -> HttpResult<TestError> {
+    Ok(match *format {
+        ResponseFormat::Json => HttpResponse::Ok().body("json"),
+        ResponseFormat::Xml => HttpResponse::Ok().body("xml"),
+    })
+}
+
+#[test]
+async fn should_pick_format_from_accept() {
+    let app = TestApp::with_service(|| test_route);
+
+    for (accept, expected) in [("application/json", "json"), ("text/xml", "xml")] {
+        let result = app
+            .get("/")
+            .header("Accept", accept)
+            .send()
+            .await
+            .expect("Error while making the request.");
+
+        assert_eq!(result.status(), StatusCode::OK);
+
+        let text = result
+            .text()
+            .await
+            .expect("Error while reading response body.");
+
+        assert_eq!(text, expected);
+    }
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_not_accept_unsupported_format() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/")
+        .header("Accept", "image/png")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_ACCEPTABLE);
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_only_pick_xml_for_xml_types() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/")
+        .header("Accept", "text/*")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_ACCEPTABLE);
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_default_to_json_on_malformed_accept() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/")
+        // the header isn't visible ascii, so it can't be parsed.
+        .header("Accept", HeaderValue::from_bytes(b"application/\xffjson").unwrap())
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "json");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_not_pick_types_with_zero_quality() {
+    let app = TestApp::with_service(|| test_route);
+
+    for accept in ["application/json;q=0", "text/html, */*;q=0"] {
+        let result = app
+            .get("/")
+            .header("Accept", accept)
+            .send()
+            .await
+            .expect("Error while making the request.");
+
+        assert_eq!(result.status(), StatusCode::NOT_ACCEPTABLE);
+    }
+
+    app.stop().await;
+}
@@ -0,0 +1,82 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{ensure_http, proof_route, ActixError, HttpResult};
+use actix_web::{http::StatusCode as ActixStatusCode, web::Path, HttpResponse};
+use reqwest::StatusCode;
+use thiserror::Error;
+use tokio::test;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("bad input")]
+    #[http_status(BadRequest)]
+    BadInput
+}
+
+#[proof_route(get("/{value}"))]
+async fn test_route(value: Path<u32>) -> HttpResult<TestError> {
+    let value = value.into_inner();
+
+    ensure_http!(value != 0, TestError::BadInput);
+    ensure_http!(value < 100, ActixStatusCode::UNPROCESSABLE_ENTITY, "Invalid input: {}", value);
+
+    Ok(HttpResponse::Ok().body("valid"))
+}
+
+#[test]
+async fn should_continue_when_condition_holds() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/5")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "valid");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_return_error_when_condition_fails() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/0")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_return_response_when_condition_fails() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/150")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "Invalid input: 150");
+
+    app.stop().await;
+}
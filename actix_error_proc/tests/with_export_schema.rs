@@ -0,0 +1,87 @@
+use actix_error_proc::openapi::{export_schemas, schemas};
+use actix_error_proc::{proof_route, ActixError, HttpResult};
+use actix_web::HttpResponse;
+use serde_json::{from_str, json, Value};
+use std::fs::read_to_string;
+use std::sync::Once;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(describe)]
+#[allow(unused)]
+enum TestError {
+    #[error("test")]
+    Test,
+    #[error("other {0}")]
+    Other(u32)
+}
+
+// the derive doesn't take common names such as a `VARIANTS` constant.
+impl TestError {
+    const VARIANTS: &'static [&'static str] = &["custom"];
+}
+
+#[allow(unused)]
+struct User;
+
+#[proof_route(get("/users/{id}"), response_schema = "Vec<User>", export_schema = "../target/test_schemas/test_route.json")]
+async fn test_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route(get("/"), head("/"), export_schema = "../target/test_schemas/test_infallible_route.json")]
+async fn test_infallible_route() -> HttpResponse {
+    HttpResponse::Ok().finish()
+}
+
+static EXPORT: Once = Once::new();
+
+fn read_schema(file: &str) -> Value {
+    EXPORT.call_once(|| export_schemas().expect("The schemas couldn't be exported."));
+
+    let schema = read_to_string(format!("{}/../target/test_schemas/{file}", env!("CARGO_MANIFEST_DIR")))
+        .expect("The schema was not exported.");
+
+    from_str(&schema)
+        .expect("The schema is not valid JSON.")
+}
+
+#[test]
+fn should_keep_user_constants() {
+    assert_eq!(TestError::VARIANTS, ["custom"]);
+}
+
+#[test]
+fn should_list_described_variants() {
+    assert_eq!(TestError::VARIANT_NAMES, ["Test", "Other"]);
+}
+
+#[test]
+fn should_record_route_schemas() {
+    let mut names = schemas().map(|schema| schema.name).collect::<Vec<_>>();
+    names.sort();
+
+    assert_eq!(names, ["test_infallible_route", "test_route"]);
+}
+
+#[test]
+fn should_export_route_schema() {
+    assert_eq!(read_schema("test_route.json"), json!([{
+        "name": "test_route",
+        "method": "GET",
+        "path": "/users/{id}",
+        "response_schema": "Vec<User>",
+        "error": "TestError",
+        "variants": ["Test", "Other"]
+    }]));
+}
+
+#[test]
+fn should_export_an_entry_per_method() {
+    let schema = read_schema("test_infallible_route.json");
+
+    assert_eq!(schema[0]["method"], "GET");
+    assert_eq!(schema[1]["method"], "HEAD");
+    assert_eq!(schema[1]["error"], Value::Null);
+    assert_eq!(schema[1]["variants"], Value::Null);
+}
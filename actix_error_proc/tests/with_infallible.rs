@@ -0,0 +1,32 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{proof_route, HttpResult};
+use actix_web::HttpResponse;
+use reqwest::StatusCode;
+use tokio::test;
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult {
+    Ok(HttpResponse::Ok().body("ok"))
+}
+
+#[test]
+async fn should_default_to_infallible() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "ok");
+
+    app.stop().await;
+}
@@ -0,0 +1,101 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{proof_route, ActixError, HttpResult, IntoHttpResult, IntoHttpResultOr};
+use actix_web::{web::Path, HttpResponse};
+use reqwest::StatusCode;
+use thiserror::Error;
+use tokio::test;
+
+#[derive(Debug)]
+struct LibError;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("library failed")]
+    #[http_status(BadGateway)]
+    Library,
+
+    #[error("not found")]
+    #[http_status(NotFound)]
+    NotFound
+}
+
+impl From<LibError> for TestError {
+    fn from(_: LibError) -> Self {
+        Self::Library
+    }
+}
+
+fn library_call(id: u32) -> Result<u32, LibError> {
+    match id {
+        0 => Err(LibError),
+        _ => Ok(id),
+    }
+}
+
+fn checked_call(id: u32) -> Result<u32, TestError> {
+    library_call(id).into_http_result()
+}
+
+fn find(id: u32) -> Option<&'static str> {
+    (id == 1).then_some("found")
+}
+
+#[proof_route(get("/{id}"))]
+async fn test_route(id: Path<u32>) -> HttpResult<TestError> {
+    let id = checked_call(*id)?;
+    let value = find(id).into_http_result_or(TestError::NotFound)?;
+
+    Ok(HttpResponse::Ok().body(value))
+}
+
+#[test]
+async fn should_convert_result_error() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/0")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_GATEWAY);
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_convert_none() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/2")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_keep_values() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/1")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "found");
+
+    app.stop().await;
+}
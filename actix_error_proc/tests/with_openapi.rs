@@ -0,0 +1,36 @@
+use actix_error_proc::openapi::{routes, RouteDoc};
+use actix_error_proc::{proof_route, HttpResult};
+use actix_web::HttpResponse;
+
+#[proof_route(get("/users"), head("/users"), openapi_tag = "users", openapi_summary = "List users")]
+#[allow(unused)]
+async fn list_users() -> HttpResult {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route(post("/sessions"), openapi_summary = "Log in")]
+#[allow(unused)]
+async fn log_in() -> HttpResult {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route(get("/health"))]
+#[allow(unused)]
+async fn health() -> HttpResult {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[test]
+fn should_record_documented_routes() {
+    let mut recorded = routes().copied().collect::<Vec<_>>();
+    recorded.sort_by_key(|route| (route.path, route.method));
+
+    assert_eq!(
+        recorded,
+        [
+            RouteDoc { method: "POST", path: "/sessions", tag: None, summary: Some("Log in") },
+            RouteDoc { method: "GET", path: "/users", tag: Some("users"), summary: Some("List users") },
+            RouteDoc { method: "HEAD", path: "/users", tag: Some("users"), summary: Some("List users") },
+        ]
+    );
+}
@@ -0,0 +1,58 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{err_body, ok_json, proof_route, HttpResult};
+use actix_web::{http::StatusCode as ActixStatusCode, web::Path, HttpResponse};
+use reqwest::StatusCode;
+use tokio::test;
+
+#[proof_route(get("/{id}"))]
+async fn test_route(id: Path<u32>) -> HttpResult<HttpResponse> {
+    match id.into_inner() {
+        0 => err_body!(ActixStatusCode::NOT_FOUND, "item {} not found", 0),
+        _ => ok_json!(vec![1, 2, 3]),
+    }
+}
+
+#[test]
+async fn should_return_ok_json() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/1")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+    assert_eq!(result.headers().get("content-type").unwrap(), "application/json");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "[1,2,3]");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_return_err_body() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/0")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "item 0 not found");
+
+    app.stop().await;
+}
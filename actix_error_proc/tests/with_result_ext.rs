@@ -0,0 +1,127 @@
+use actix_error_proc::ext::HttpResultExt;
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{proof_route, ActixError, HttpResult};
+use actix_web::{http::StatusCode as ActixStatusCode, web::Path, HttpResponse};
+use reqwest::StatusCode;
+use thiserror::Error;
+use tokio::test;
+
+#[derive(ActixError, Error, Debug)]
+enum InnerError {
+    #[error("inner")]
+    #[http_status(NotFound)]
+    Inner
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("wrapped: {0}")]
+    #[http_status(BadGateway)]
+    Wrapped(#[from] InnerError)
+}
+
+fn lookup(id: u32) -> Result<&'static str, InnerError> {
+    match id {
+        0 => Err(InnerError::Inner),
+        _ => Ok("found"),
+    }
+}
+
+#[proof_route(get("/map/{id}"))]
+async fn map_route(id: Path<u32>) -> HttpResult<TestError> {
+    let value = lookup(*id).map_err_into::<TestError>()?;
+    Ok(HttpResponse::Ok().body(value))
+}
+
+#[proof_route(get("/status/{id}"))]
+async fn status_route(id: Path<u32>) -> HttpResult<HttpResponse> {
+    let value = lookup(*id).context_status(ActixStatusCode::GONE)?;
+    Ok(HttpResponse::Ok().body(value))
+}
+
+#[proof_route(get("/500/{id}"))]
+async fn internal_route(id: Path<u32>) -> HttpResult<HttpResponse> {
+    let value = lookup(*id).or_500()?;
+    Ok(HttpResponse::Ok().body(value))
+}
+
+#[test]
+async fn should_map_error_into() {
+    let app = TestApp::with_service(|| map_route);
+
+    let result = app
+        .get("/map/0")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_GATEWAY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "wrapped: inner");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_override_status() {
+    let app = TestApp::with_service(|| status_route);
+
+    let result = app
+        .get("/status/0")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::GONE);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "inner");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_convert_into_internal_error() {
+    let app = TestApp::with_service(|| internal_route);
+
+    let result = app
+        .get("/500/0")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "inner");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_keep_ok_values() {
+    let app = TestApp::with_service(|| status_route);
+
+    let result = app
+        .get("/status/1")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    app.stop().await;
+}
@@ -0,0 +1,86 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{proof_route, ActixError, HttpResult, RetryBudget};
+use actix_web::web::{scope, Data};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Mutex;
+use thiserror::Error;
+use tokio::test;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("unavailable")]
+    #[http_status(ServiceUnavailable)]
+    #[actix_error(retriable)]
+    Unavailable,
+
+    #[error("bad request")]
+    #[http_status(BadRequest)]
+    BadRequest
+}
+
+#[derive(Default)]
+struct TestBudget {
+    retries: Mutex<HashMap<IpAddr, u32>>
+}
+
+impl RetryBudget for TestBudget {
+    fn consume(&self, client: IpAddr) -> bool {
+        let mut retries = self.retries.lock().unwrap();
+        let retries = retries.entry(client).or_insert(0);
+
+        *retries += 1;
+        *retries <= 2
+    }
+}
+
+#[proof_route(get("/"), retry_budget = "TestBudget")]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Unavailable)
+}
+
+#[proof_route(get("/"), retry_budget = "TestBudget")]
+async fn test2_route() -> HttpResult<TestError> {
+    Err(TestError::BadRequest)
+}
+
+#[test]
+async fn should_exhaust_retry_budget() {
+    let budget = Data::new(TestBudget::default());
+    let app = TestApp::with_service(move || scope("").app_data(budget.clone()).service(test_route));
+
+    for status in [
+        StatusCode::SERVICE_UNAVAILABLE,
+        StatusCode::SERVICE_UNAVAILABLE,
+        StatusCode::TOO_MANY_REQUESTS,
+    ] {
+        let result = app
+            .get("/")
+            .send()
+            .await
+            .expect("Error while making the request.");
+
+        assert_eq!(result.status(), status);
+    }
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_not_consume_budget_on_non_retriable() {
+    let budget = Data::new(TestBudget::default());
+    let app = TestApp::with_service(move || scope("").app_data(budget.clone()).service(test2_route));
+
+    for _ in 0..3 {
+        let result = app
+            .get("/")
+            .send()
+            .await
+            .expect("Error while making the request.");
+
+        assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+    }
+
+    app.stop().await;
+}
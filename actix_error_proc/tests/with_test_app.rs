@@ -0,0 +1,63 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{proof_route, ActixError, HttpResult};
+use actix_web::HttpResponse;
+use reqwest::StatusCode;
+use thiserror::Error;
+use tokio::test;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test
+}
+
+#[proof_route(get("/fail"))]
+async fn fail_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[proof_route(post("/ok"))]
+async fn ok_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body("ok"))
+}
+
+#[test]
+async fn should_serve_the_route() {
+    let app = TestApp::with_service(|| fail_route);
+
+    let result = app
+        .get("/fail")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "test");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_build_urls_from_the_address() {
+    let app = TestApp::with_service(|| ok_route);
+
+    assert!(app.address().starts_with("http://127.0.0.1:"));
+    assert_eq!(app.url("/ok"), format!("{}/ok", app.address()));
+
+    let result = app
+        .post("/ok")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    app.stop().await;
+}
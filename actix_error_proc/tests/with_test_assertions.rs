@@ -0,0 +1,49 @@
+use actix_error_proc::testing::{assert_body, assert_status, TestApp};
+use actix_error_proc::{proof_route, ActixError, HttpResult};
+use actix_web::http::StatusCode as ActixStatusCode;
+use reqwest::StatusCode;
+use thiserror::Error;
+use tokio::test;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[test]
+async fn should_assert_status_and_body() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_status!(result, StatusCode::BAD_REQUEST);
+    assert_status!(result, ActixStatusCode::BAD_REQUEST);
+    assert_body!(result, "test");
+
+    app.stop().await;
+}
+
+#[test]
+#[should_panic(expected = "Expected the response status to be 404 but it was 400.")]
+async fn should_report_the_actual_status() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_status!(result, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,140 @@
+use actix_error_proc::testing::TestApp;
+use actix_error_proc::{proof_route, unify_errors, ActixError, HttpResult};
+use thiserror::Error;
+use reqwest::StatusCode;
+use tokio::test;
+
+mod auth {
+    use actix_error_proc::ActixError;
+    use thiserror::Error;
+
+    #[derive(ActixError, Error, Debug)]
+    #[actix_error(describe)]
+    pub enum AuthError {
+        #[error("unauthorized")]
+        #[http_status(Unauthorized)]
+        Unauthorized,
+        #[error("the token of {user} expired")]
+        #[http_status(Forbidden)]
+        Expired { user: String }
+    }
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(default_status = "ServiceUnavailable", describe)]
+pub enum DatabaseError {
+    #[error("user {0} not found")]
+    #[http_status(NotFound)]
+    NotFound(u32),
+    #[error("connection lost")]
+    Connection
+}
+
+// the hidden macro isn't named as the error, which a glob import may also bring.
+#[deny(warnings)]
+mod glob {
+    use actix_error_proc::*;
+    use thiserror::*;
+
+    #[derive(ActixError, Error, Debug)]
+    #[actix_error(describe)]
+    pub enum Error {
+        #[error("conflict")]
+        #[http_status(Conflict)]
+        Conflict
+    }
+}
+
+unify_errors!(
+    #[derive(Error, Debug)]
+    #[allow(unused)]
+    TestGlobError { glob::Error }
+);
+
+unify_errors!(
+    #[derive(Error, Debug)]
+    pub TestError { auth::AuthError, DatabaseError }
+);
+
+unify_errors!(
+    #[derive(Debug)]
+    #[allow(unused)]
+    #[actix_error(with_display = "failed", with_status_fn)]
+    TestPlainError { DatabaseError }
+);
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(auth::AuthError::Unauthorized)?
+}
+
+#[proof_route(get("/"))]
+async fn test_database_route() -> HttpResult<TestError> {
+    Err(DatabaseError::NotFound(1))?
+}
+
+#[test]
+async fn should_respond_as_first_error() {
+    let app = TestApp::with_service(|| test_route);
+
+    let result = app
+        .get("/")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "unauthorized");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_respond_as_second_error() {
+    let app = TestApp::with_service(|| test_database_route);
+
+    let result = app
+        .get("/")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "user 1 not found");
+
+    app.stop().await;
+}
+
+#[test]
+async fn should_copy_every_variant() {
+    let expired = TestError::from(auth::AuthError::Expired { user: "john".into() });
+
+    assert!(matches!(&expired, TestError::Expired { user } if user == "john"));
+    assert_eq!(expired.to_string(), "the token of john expired");
+    assert!(matches!(TestError::from(DatabaseError::NotFound(2)), TestError::NotFound(2)));
+}
+
+#[test]
+async fn should_keep_default_status() {
+    let status = TestPlainError::from(DatabaseError::Connection).status();
+
+    assert_eq!(status.as_u16(), 503);
+    assert_eq!(TestPlainError::Connection.to_string(), "failed");
+}
+
+#[test]
+async fn should_unify_glob_imported_names() {
+    assert!(matches!(TestGlobError::from(glob::Error::Conflict), TestGlobError::Conflict));
+}
@@ -1,9 +1,13 @@
-use core::panic;
+use core::cmp::Reverse;
+use core::hash::{Hash, Hasher};
+use std::hash::DefaultHasher;
 use proc_macro::{Span, TokenStream};
-use quote::{format_ident, quote, ToTokens};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned, ToTokens};
 use syn::{
-    parse_macro_input, spanned::Spanned, Data, DeriveInput, Expr, ExprCall, ExprLit, Fields, FnArg,
-    Ident, ItemFn, Lit, Meta,
+    parse_macro_input, parse_quote, punctuated::Punctuated, spanned::Spanned, Attribute, Data, DeriveInput, Expr,
+    ExprCall, ExprLit, Field, Fields, FnArg, GenericArgument, GenericParam, Ident, ImplItem, ItemFn, ItemImpl, Lit, LitBool, LitInt, LitStr, Meta, MetaNameValue, Pat, Path,
+    PathArguments, ReturnType, Token, Type, TypeParamBound, Variant, Visibility,
 };
 
 /// This macro is helps the HttpResult type to infer
@@ -27,13 +31,121 @@ use syn::{
 /// }
 /// ```
 ///
+/// The `#[http_status(...)]` attribute takes the name of an `actix_web::HttpResponse`
+/// builder method, a numeric status code for less common status codes or a path
+/// to a `StatusCode` constant, such as `http::StatusCode::NOT_FOUND`. Unknown names
+/// are reported on the attribute together with the closest known name, and a warning
+/// is emitted for success statuses such as `Ok`, which belong to the `Ok` of the route,
+/// unless the response has no body with `body = "empty"`. The attribute can only be set
+/// once on each variant.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The user is not old enough.")]
+///     #[http_status(422)]
+///     TooYoung,
+///
+///     #[error("Unavailable for legal reasons.")]
+///     #[http_status(451)]
+///     Censored,
+///
+///     #[error("The user was not found.")]
+///     #[http_status(http::StatusCode::NOT_FOUND)]
+///     UserNotFound
+/// }
+/// ```
+///
+/// The status can be followed by modifiers, `body = "empty"` sends the response without
+/// a body for statuses such as `204 No Content` or `304 Not Modified`, in which case the
+/// message isn't formatted and a transformer receives an empty string.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The resource was not modified.")]
+///     #[http_status(NotModified, body = "empty")]
+///     NotModified
+/// }
+/// ```
+///
+/// The `message = "..."` modifier sends a static message instead of the formatted error,
+/// so the `Display` implementation can keep internal details for logging, the message
+/// is still wrapped by `json_body`.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The query {0} failed.")]
+///     #[http_status(InternalServerError, message = "Something went wrong.")]
+///     Database(String)
+/// }
+/// ```
+///
+/// The `body_fn = "path"` modifier builds the body with a function that receives the
+/// error by reference and returns any `actix_web::body::MessageBody`, so the fields of
+/// the variant can be used without a transformer, it can't be used with `body = "empty"`
+/// or a transformer.
+///
+/// ```ignore
+/// fn missing_field_body(error: &SomeError) -> String {
+///     match error {
+///         SomeError::MissingField(field) => format!("{{\"missing\":\"{field}\"}}"),
+///     }
+/// }
+///
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The field {0} is missing.")]
+///     #[http_status(BadRequest, body_fn = "missing_field_body")]
+///     MissingField(String)
+/// }
+/// ```
+///
+/// Enums without variants are supported as error types that can't be built, such as
+/// the error of a route that never fails.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum Never {}
+/// ```
+///
+/// On `#[non_exhaustive]` enums the generated matches also have a fallback arm, which
+/// responds with a `500 Internal Server Error` and an `unknown error` body.
+///
+/// Structs are also supported, in which case the whole struct is a single
+/// response and the `#[http_status(...)]` attribute is set on the struct,
+/// where it's required unless the `default_status` variable is set.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[error("The field {field} is invalid: {message}")]
+/// #[http_status(BadRequest)]
+/// struct ValidationError {
+///     field: String,
+///     message: String
+/// }
+/// ```
+///
+/// Generic enums and structs are supported too, their type and lifetime parameters, bounds and
+/// `where` clauses are carried to the generated implementations.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum ApiError<T: Display + Debug> {
+///     #[error("The value {0} is not valid.")]
+///     #[http_status(BadRequest)]
+///     InvalidValue(T)
+/// }
+/// ```
+///
 /// You can also add an attribute to the enum that lets you
 /// modify the behaviour of how the enum is converted into an
 /// `actix_web::HttpResponse`.
 ///
-/// The only variable available for now is a transformer, which is
-/// a function that transforms the request, letting you add headers
-/// and other things in the response.
+/// The variables are set as a comma separated list, one of them is the
+/// transformer, which is a function that transforms the request, letting
+/// you add headers and other things in the response.
 ///
 /// ```ignore
 /// use actix_error_proc::{ActixError, Error}; // Error is a thiserror re export.
@@ -55,250 +167,4594 @@ use syn::{
 ///
 /// And after that all the responses derived from the enum should have your own
 /// format.
-#[proc_macro_derive(ActixError, attributes(http_status, actix_error))]
-pub fn derive_actix_error(input: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(input as DeriveInput);
-    let enum_name = &input.ident;
+///
+/// The variables can also be split across many `actix_error` attributes, which are merged,
+/// setting the same variable more than once is an error except for `merge_from`.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(transformer = "transform_error")]
+/// #[actix_error(default_status = "BadRequest", json_body)]
+/// enum SomeError {
+///  // ...
+/// }
+/// ```
+///
+/// The signature of the transformer is checked against
+/// `fn(HttpResponseBuilder, String) -> HttpResponse`, a mismatch is reported on the
+/// `transformer = "..."` value.
+///
+/// A single variant can also have its own transformer with the `http_transformer`
+/// attribute, which is used instead of the enum transformer for that variant.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(transformer = "transform_error")]
+/// enum SomeError {
+///     #[error("The user was not found.")]
+///     #[http_status(NotFound)]
+///     #[http_transformer = "transform_not_found"]
+///     UserNotFound,
+///
+///     // uses `transform_error`.
+///     #[error("The body is not valid.")]
+///     #[http_status(BadRequest)]
+///     InvalidBody
+/// }
+/// ```
+///
+/// With the `async_transformer` feature enabled the `async_transformer` variable takes
+/// an `async fn(HttpResponseBuilder, String) -> HttpResponse` instead, since it can't
+/// be awaited from `Into<HttpResponse>` the enum gets an `async_into()` method and
+/// `.into()` no longer compiles, routes using it must set the `async_transformer`
+/// flag of `proof_route`.
+///
+/// ```ignore
+/// async fn transform_error(mut res: HttpResponseBuilder, fmt: String) -> HttpResponse {
+///     ERROR_TRACKER.report(&fmt).await;
+///     res.json(json!({"error": fmt}))
+/// }
+///
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(async_transformer = "transform_error")]
+/// enum SomeError {
+///  // ...
+/// }
+///
+/// #[proof_route(get("/users"), async_transformer)]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+/// ```
+///
+/// If the error messages may carry raw binary data, with the `base64` feature enabled
+/// you can set the `body_encoding` variable to `"base64"`, the formatted message is then
+/// base64 encoded before being set as the body (or passed to the transformer) and a
+/// `Content-Transfer-Encoding: base64` header is added to the response. Bodies
+/// built by `body_fn` are sent as they are, without the header.
+///
+/// This requires the `base64` crate to be a dependency of your crate, without the
+/// feature the variable is a compile error.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(body_encoding = "base64")]
+/// enum SomeError {
+///  // ...
+/// }
+/// ```
+///
+/// Flags are set by their name only, the `secure_display` flag makes release builds
+/// only expose the part of the message before the first `: `, so messages such as
+/// `"operation failed: <internal details>"` are sent as `"operation failed"`.
+/// Debug builds keep the whole message.
+///
+/// Messages are formatted with `{:#}` by default, which may include the source of the
+/// error, `expose_source = false` formats them with `{}` instead and the
+/// `#[http_expose_source(true)]` attribute keeps the alternate format for a single variant.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(expose_source = false)]
+/// enum SomeError {
+///     #[error("The database failed.")]
+///     Database(#[from] DatabaseError),
+///
+///     #[error("The request is invalid.")]
+///     #[http_status(BadRequest)]
+///     #[http_expose_source(true)]
+///     Invalid(#[from] ValidationError)
+/// }
+/// ```
+///
+/// Variants holding dynamic errors such as `Box<dyn std::error::Error + Send + Sync>` need
+/// nothing special, the message is the one of their `#[error(...)]` attribute, and since
+/// the alternate format of the boxed error is up to its type, interpolating it with `{0}`
+/// keeps the message predictable.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The database failed: {0}")]
+///     #[http_status(ServiceUnavailable)]
+///     Database(Box<dyn std::error::Error + Send + Sync>)
+/// }
+/// ```
+///
+/// The `backtrace` flag adds an `X-Backtrace` header with the backtrace of the place
+/// where the error is converted into a response, with its frames joined by ` | ` and
+/// cut with ` | ...` after 4kB to fit the header limits of proxies. It's always captured
+/// in debug builds, while release builds only capture it when the `RUST_BACKTRACE` or
+/// `RUST_LIB_BACKTRACE` environment variables enable it.
+///
+/// A backtrace exposes the paths, dependencies and internals of the server to anyone
+/// who gets the response, so there shouldn't be a `RUST_BACKTRACE` in production, or
+/// the flag should be set only for debug builds with
+/// `#[cfg_attr(debug_assertions, actix_error(backtrace))]`.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(backtrace)]
+/// enum SomeError {
+///     // ...
+/// }
+/// ```
+///
+/// The `http3_compatible` flag removes the connection specific headers that are
+/// forbidden in HTTP/3 (`Connection`, `Keep-Alive`, `Proxy-Connection`,
+/// `Transfer-Encoding` and `Upgrade`) from the response, including the ones
+/// added by a transformer.
+///
+/// The `impl_into_string` flag implements `From<SomeError> for String`, which
+/// returns the message of the response following `message = "..."`,
+/// `http_expose_source` and `secure_display`, or the body built by `body_fn` when
+/// it can be read without polling, useful to store errors in logs or databases
+/// without building a response. Transparent variants return the body of the response
+/// of the error they hold, which doesn't need to implement `Display`.
+///
+/// The `default_status` variable sets the status of the variants without an
+/// `#[http_status(...)]` attribute instead of `InternalServerError`, it accepts the
+/// same values as `#[http_status(...)]` and on structs it makes that attribute optional.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(default_status = "UnprocessableEntity")]
+/// enum ValidationError {
+///     #[error("The name is too long.")]
+///     NameTooLong, // 422 Unprocessable Entity.
+///
+///     #[error("The user already exists.")]
+///     #[http_status(Conflict)]
+///     AlreadyExists
+/// }
+/// ```
+///
+/// The `impl_response_error` flag implements `actix_web::ResponseError` for the enum,
+/// where `status_code()` returns the status of the variant and `error_response()`
+/// builds the same response as `Into<HttpResponse>`, so the enum can be used wherever
+/// actix_web expects a `ResponseError`. The conversion into `actix_web::Error` then
+/// comes from actix_web and uses that response. Transparent and delegated variants call
+/// `status_code()` and `error_response()` on the error they hold, which must implement
+/// `ResponseError` as well.
+///
+/// The `skip_into_impl` flag skips the `Into<HttpResponse>` implementation, so you
+/// can write your own conversion, for example with `impl From<SomeError> for HttpResponse`
+/// which would otherwise conflict with the generated one. Routes keep working as long
+/// as the enum converts into an `HttpResponse`. The flag can't be combined with an
+/// `async_transformer`, which has no `Into` implementation.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(skip_into_impl)]
+/// enum SomeError {
+///     // ...
+/// }
+///
+/// impl From<SomeError> for HttpResponse {
+///     fn from(error: SomeError) -> Self {
+///         HttpResponse::BadRequest().body(error.to_string())
+///     }
+/// }
+/// ```
+///
+/// With the `serde_json` feature enabled the `json_body` flag sends the message as
+/// `{"error": "<message>"}` with a `Content-Type: application/json` header, a transformer
+/// receives that JSON text as its second argument. This requires the `serde_json`
+/// crate to be a dependency of your crate.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(json_body)]
+/// enum SomeError {
+///  // ...
+/// }
+/// ```
+///
+/// The `http_json_body` attribute changes the JSON body of a single variant, even if the
+/// enum doesn't have the `json_body` flag. The `key` variable sets the name of the field
+/// holding the message and the `object` variable calls a `fn(&SomeError) -> serde_json::Value`
+/// to build the whole body, only one of them can be set. The transformer still receives the
+/// JSON text.
+///
+/// ```ignore
+/// fn not_found_body(error: &SomeError) -> serde_json::Value {
+///     json!({"error": "not_found", "description": error.to_string()})
+/// }
+///
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(json_body)]
+/// enum SomeError {
+///     #[error("The body is not valid.")]
+///     #[http_json_body(key = "message")]
+///     InvalidBody, // {"message": "The body is not valid."}
+///
+///     #[error("The user was not found.")]
+///     #[http_json_body(object = "not_found_body")]
+///     UserNotFound
+/// }
+/// ```
+///
+/// The `code_field` variable adds the number of the `error_code` attribute of a variant
+/// to its JSON body under the given key, variants without the attribute don't have the
+/// field and bodies built with `object` are left as they are.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(json_body, code_field = "code")]
+/// enum SomeError {
+///     #[error("The user is banned.")]
+///     #[error_code(1042)]
+///     Banned, // {"code": 1042, "error": "The user is banned."}
+///
+///     #[error("Something went wrong.")]
+///     Internal // {"error": "Something went wrong."}
+/// }
+/// ```
+///
+/// The `error_key_case` variable sets the case of the `error` key of the JSON bodies, which
+/// is the only key the macro names, with the same names as serde's `rename_all`, such as
+/// `"camelCase"`, `"PascalCase"` or `"SCREAMING_SNAKE_CASE"`, it requires the `json_body`
+/// flag. The keys set with `key` or `code_field` are used as they are written.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(json_body, error_key_case = "PascalCase")]
+/// enum SomeError {
+///     #[error("Something went wrong.")]
+///     Internal // {"Error": "Something went wrong."}
+/// }
+/// ```
+///
+/// The `merge_from` variable implements `From<OtherError>` for the enum, the value is
+/// moved into the variant that holds only an `OtherError` or otherwise converted with
+/// `Into` into the variant marked with the `catch_all` flag, which must have a single
+/// field. `merge_from` can be set more than once to merge many error types.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(merge_from = "AuthError", merge_from = "std::io::Error")]
+/// enum AppError {
+///     #[error("{0}")]
+///     Auth(AuthError), // AuthError is moved here.
+///
+///     #[error("{0}")]
+///     #[actix_error(catch_all)]
+///     Other(String) // io::Error is converted with `Into<String>`.
+/// }
+/// ```
+///
+/// The `emit_event` variable calls `EventType::emit(self.clone())` before the error is
+/// converted, so side effects such as publishing the error to an event bus don't
+/// need to live in the transformer. `EventType` must have an associated function
+/// `emit(error: SomeError)`, usually from your own `ErrorEvent` trait, and the
+/// enum must implement `Clone`.
+///
+/// ```ignore
+/// trait ErrorEvent<E> {
+///     fn emit(error: E);
+/// }
+///
+/// struct ErrorOccurred;
+///
+/// impl ErrorEvent<SomeError> for ErrorOccurred {
+///     fn emit(error: SomeError) {
+///         // publish the error.
+///     }
+/// }
+///
+/// #[derive(ActixError, Error, Debug, Clone)]
+/// #[actix_error(emit_event = "ErrorOccurred")]
+/// enum SomeError {
+///  // ...
+/// }
+/// ```
+///
+/// The `retriable` flag generates a `fn is_retriable(&self) -> bool` method which
+/// tells whether clients may retry a request that failed with the error, this is
+/// used by the `retry_budget` option of `proof_route`.
+///
+/// The `describe` flag generates a `pub const VARIANT_NAMES: &[&str]` constant with the
+/// names of the variants in declaration order, which the `export_schema` option of
+/// `proof_route` lists, and a hidden macro with the definition of the error, which
+/// `unify_errors!` reads.
+///
+/// The `with_status_fn` flag generates a `pub fn status(&self) -> StatusCode` method which
+/// returns the status code of the response the error converts into, transparent variants
+/// return the status of the error they hold, which must also have the method.
+///
+/// The `with_is_client_error` and `with_is_server_error` flags generate a
+/// `pub fn is_client_error(&self) -> bool` and a `pub fn is_server_error(&self) -> bool`
+/// method respectively, which tell whether the status of the error is a 4xx or a 5xx one.
+///
+/// The `impl_try_from_status` flag implements `TryFrom<u16>` for the enum, which returns
+/// the variant with the given status code and the highest `priority`, or the first declared
+/// one among equal priorities, or `Err(())` if there is none,
+/// the fields of tuple variants are built with `Default` while variants with named fields
+/// can't be built and transparent variants are skipped.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(impl_try_from_status)]
+/// enum SomeError {
+///     #[error("Not found.")]
+///     #[http_status(NotFound)]
+///     NotFound,
+///
+///     #[error("Invalid input: {0}")]
+///     #[http_status(BadRequest)]
+///     Invalid(String) // built as `Invalid(String::default())`.
+/// }
+///
+/// assert!(matches!(SomeError::try_from(404), Ok(SomeError::NotFound)));
+/// ```
+///
+/// The `include_variant_name` flag prefixes the body of the responses with the name of the
+/// variant in `SCREAMING_SNAKE_CASE`, such as `[USER_NOT_FOUND] The user was not found.`, the
+/// `variant_case = "snake"` option uses `snake_case` instead and transformers receive the
+/// prefixed message.
+///
+/// The `with_display` option generates the `Display` implementation from a format string
+/// applied to every variant, for error types that don't use `thiserror`, the arguments are
+/// captured by name such as `{self:?}`.
+///
+/// ```ignore
+/// #[derive(ActixError, Debug)]
+/// #[actix_error(with_display = "Error: {self:?}")]
+/// enum SomeError {
+///     #[http_status(NotFound)]
+///     NotFound
+/// }
+/// ```
+///
+/// With the `tracing` feature enabled the `log_level` variable emits a `tracing` event with
+/// the formatted error at that level before the response is built, which the `log` attribute
+/// sets per variant, the levels are `"error"`, `"warn"`, `"info"`, `"debug"` and `"trace"`.
+/// Variants without a level aren't logged and without the feature the levels have no effect.
+/// This requires the `tracing` crate to be a dependency of your crate.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(log_level = "warn")]
+/// enum SomeError {
+///     #[error("The user was not found.")]
+///     #[http_status(NotFound)]
+///     UserNotFound,
+///     #[error("The database failed.")]
+///     #[log(level = "error")]
+///     DatabaseError
+/// }
+/// ```
+///
+/// With the `sentry` feature enabled the `capture_to_sentry` attribute sends the error of a
+/// variant to Sentry with `sentry::capture_error` before the response is built, without the
+/// feature it has no effect. This requires the `sentry` crate to be a dependency of your crate,
+/// and the Sentry client is configured by your application with `sentry::init` as usual.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The database failed.")]
+///     #[capture_to_sentry]
+///     DatabaseError
+/// }
+/// ```
+///
+/// The `actix_error` attribute can also be set on variants, where the `priority`
+/// variable sets the order in which the variants are matched in the generated
+/// code, higher priorities are matched first and variants without it have a
+/// priority of 0. When several variants could be picked, such as for a shared status in
+/// `impl_try_from_status` or the default error of `#[or_default]`, the one with the highest
+/// priority is picked, the first declared one among equal priorities.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("A database error occurred: {0:#}")]
+///     #[actix_error(priority = 100)]
+///     DatabaseError(#[from] /* ... */)
+/// }
+/// ```
+///
+/// The `retriable` flag can also be set on variants to only mark some of them.
+///
+/// The `http_header` attribute adds a header to the response of a variant, it can be
+/// set more than once and the headers are added before the builder is passed to the
+/// transformer.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The user is banned.")]
+///     #[http_status(Forbidden)]
+///     #[http_header("X-Error-Code", "E001")]
+///     #[http_header("X-Api-Version", "2")]
+///     Banned
+/// }
+/// ```
+///
+/// The `http_retry_after` attribute adds a `Retry-After` header with that many seconds,
+/// which is useful for rate limiting errors.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("Too many requests, try again later.")]
+///     #[http_status(TooManyRequests)]
+///     #[http_retry_after(60)]
+///     RateLimited
+/// }
+/// ```
+///
+/// The `http_location` attribute adds a `Location` header for redirections, the
+/// `http_status` of the variant must be a 3xx status. Locations that are only known
+/// at runtime have to be set with a transformer.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The resource moved.")]
+///     #[http_status(PermanentRedirect)]
+///     #[http_location("/v2/users")]
+///     Moved
+/// }
+/// ```
+///
+/// The `http_content_type` attribute sets the `Content-Type` of the response of a variant,
+/// which takes precedence over the one of `json_body`, values without a `/` generate
+/// a warning since they aren't MIME types.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(json_body)]
+/// enum SomeError {
+///     #[error("The body is not valid.")]
+///     #[http_status(BadRequest)]
+///     #[http_content_type("application/problem+json")]
+///     InvalidBody
+/// }
+/// ```
+///
+/// The `actix_transparent` attribute forwards the conversion of a variant to the error it
+/// holds, which must be its only field and implement `Into<HttpResponse>`, so wrapped
+/// errors keep their own status and body.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum RouteError {
+///     #[error(transparent)]
+///     #[actix_transparent]
+///     Auth(AuthError), // responds as `AuthError` does.
+///
+///     #[error(transparent)]
+///     #[actix_transparent]
+///     Database(DatabaseError)
+/// }
+/// ```
+///
+/// The status of a transparent variant comes from the inner error, so it can't have an
+/// `http_status` attribute. A variant with `#[error(transparent)]` and no `actix_transparent`
+/// only forwards the message of the inner error, and responds with the status of its
+/// `http_status` attribute or the default one, which is `500 Internal Server Error`
+/// unless `default_status` is set.
+///
+/// The `delegate_to` variable of the `actix_error` attribute of a variant forwards the
+/// conversion as `actix_transparent` does, naming the type it's delegated to, so it responds with
+/// `<OtherError as Into<HttpResponse>>::into(inner)` and a field of another type is a
+/// compile error. With thiserror's `#[from]` on the field the wrapped error converts
+/// with `?` without any other code.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum RouteError {
+///     #[error(transparent)]
+///     #[actix_error(delegate_to = "DatabaseError")]
+///     Database(#[from] DatabaseError) // responds as `DatabaseError` does.
+/// }
+/// ```
+#[proc_macro_derive(ActixError, attributes(http_status, http_transformer, http_json_body, http_header, http_retry_after, http_location, http_content_type, actix_transparent, actix_error, error_code, http_expose_source, log, capture_to_sentry))]
+pub fn derive_actix_error(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_actix_error(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates the `ActixError` implementations, errors point to the tokens that caused them.
+fn expand_actix_error(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let type_name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut units = match &input.data {
+        Data::Enum(data_enum) => data_enum
+            .variants
+            .iter()
+            .map(|variant| {
+                let variant_name = &variant.ident;
+
+                Ok(ErrorUnit {
+                    ident: variant_name,
+                    path: quote! { Self::#variant_name },
+                    attrs: &variant.attrs,
+                    fields: &variant.fields,
+                    options: variant_options(variant)?,
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?,
+        Data::Struct(data_struct) => {
+            vec![ErrorUnit {
+                ident: type_name,
+                path: quote! { Self },
+                attrs: &input.attrs,
+                fields: &data_struct.fields,
+                options: VariantOptions::default(),
+            }]
+        }
+        Data::Union(data_union) => {
+            return Err(syn::Error::new_spanned(
+                data_union.union_token,
+                "ActixError can only be derived for enums and structs.",
+            ));
+        }
+    };
+
+    let mut transformer = None;
+    let mut async_transformer = None;
+    let mut base64_body = false;
+    let mut secure_display = false;
+    let mut http3_compatible = false;
+    let mut impl_into_string = false;
+    let mut merge_from = Vec::new();
+    let mut emit_event = None;
+    let mut retriable = false;
+    let mut describe = false;
+    let mut with_status_fn = false;
+    let mut impl_try_from_status = false;
+    let mut include_variant_name = false;
+    let mut snake_case_variants = false;
+    let mut code_field = None;
+    let mut expose_source = true;
+    let mut with_is_client_error = false;
+    let mut with_is_server_error = false;
+    let mut impl_response_error = false;
+    let mut skip_into_impl = None;
+    let mut backtrace = false;
+    let mut json_body = false;
+    let mut default_status = None;
+    let mut default_status_tokens = None;
+    let mut with_display = None;
+    let mut default_or_variant = None;
+    let mut error_key = None;
+    let mut default_status_warning = None;
+    let mut log_level = None;
+
+    // the attributes are merged, options set in more than one place are rejected.
+    let mut options = Vec::new();
+
+    for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("actix_error")) {
+        options.extend(
+            attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+                .map_err(|error| syn::Error::new(error.span(), "Expected a comma separated list of options."))?,
+        );
+    }
+
+    let mut seen_options = Vec::<&Path>::new();
+
+    // `merge_from` is the only option meant to be repeated.
+    for path in options.iter().map(Meta::path).filter(|path| !path.is_ident("merge_from")) {
+        if seen_options.contains(&path) {
+            return Err(syn::Error::new_spanned(
+                path,
+                format!("The `{}` option is set more than once.", path.to_token_stream()),
+            ));
+        }
+
+        seen_options.push(path);
+    }
+
+    for option in options {
+        let option = match option {
+            Meta::Path(flag) if flag.is_ident("secure_display") => {
+                secure_display = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("backtrace") => {
+                backtrace = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("http3_compatible") => {
+                http3_compatible = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("impl_into_string") => {
+                impl_into_string = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("retriable") => {
+                retriable = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("describe") => {
+                describe = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("with_status_fn") => {
+                with_status_fn = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("include_variant_name") => {
+                include_variant_name = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("impl_try_from_status") => {
+                impl_try_from_status = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("with_is_client_error") => {
+                with_is_client_error = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("with_is_server_error") => {
+                with_is_server_error = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("json_body") => {
+                if !cfg!(feature = "serde_json") {
+                    return Err(syn::Error::new_spanned(
+                        flag,
+                        "The `json_body` flag requires the `serde_json` feature.",
+                    ));
+                }
+
+                json_body = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("impl_response_error") => {
+                impl_response_error = true;
+                continue;
+            }
+            Meta::Path(flag) if flag.is_ident("skip_into_impl") => {
+                skip_into_impl = Some(flag);
+                continue;
+            }
+            Meta::NameValue(option) if option.path.is_ident("default_status") => {
+                // the status can be quoted or written as in `#[http_status(...)]`.
+                let tokens = match &option.value {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(status),
+                        ..
+                    }) => status.parse::<TokenStream2>(),
+                    value => Ok(value.to_token_stream()),
+                };
+
+                let tokens = tokens?;
+                let status = HttpStatus::parse_tokens(tokens.clone(), &option.value)?;
+
+                if status.is_success() {
+                    default_status_warning = Some(warning(&option.value, SUCCESS_STATUS_WARNING));
+                }
+
+                default_status = Some(status);
+                default_status_tokens = Some(tokens);
+                continue;
+            }
+            Meta::NameValue(option) if option.path.is_ident("expose_source") => {
+                expose_source = flag_value(Some(option.value))?;
+                continue;
+            }
+            Meta::NameValue(option) => option,
+            option => {
+                return Err(syn::Error::new_spanned(
+                    option,
+                    "Expected a `name = \"value\"` option or a known flag.",
+                ));
+            }
+        };
+
+        let literal = if let Expr::Lit(ExprLit {
+            lit: Lit::Str(lit_str),
+            ..
+        }) = &option.value
+        {
+            lit_str
+        } else {
+            return Err(syn::Error::new_spanned(
+                &option.value,
+                "Expected a string literal value.",
+            ));
+        };
+
+        let value = literal.value();
+
+        // transformers are parsed from the literal to keep its span for the signature check.
+        if option.path.is_ident("transformer") {
+            transformer = Some(parse_literal::<Path>(literal, "Expected `transformer` to be a function path.")?);
+        } else if option.path.is_ident("async_transformer") {
+            if !cfg!(feature = "async_transformer") {
+                return Err(syn::Error::new_spanned(
+                    &option.path,
+                    "The `async_transformer` option requires the `async_transformer` feature.",
+                ));
+            }
+
+            async_transformer = Some(parse_literal::<Path>(
+                literal,
+                "Expected `async_transformer` to be a function path.",
+            )?);
+        } else if option.path.is_ident("emit_event") {
+            emit_event = Some(parse_literal::<Path>(literal, "Expected `emit_event` to be a type path.")?);
+        } else if option.path.is_ident("merge_from") {
+            merge_from.push(parse_literal::<Type>(literal, "Expected `merge_from` to be a type.")?);
+        } else if option.path.is_ident("body_encoding") {
+            if !cfg!(feature = "base64") {
+                return Err(syn::Error::new_spanned(
+                    &option.path,
+                    "The `body_encoding` option requires the `base64` feature.",
+                ));
+            }
+
+            if value != "base64" {
+                return Err(syn::Error::new_spanned(
+                    literal,
+                    "The only supported `body_encoding` is \"base64\".",
+                ));
+            }
+
+            base64_body = true;
+        } else if option.path.is_ident("code_field") {
+            if !cfg!(feature = "serde_json") {
+                return Err(syn::Error::new_spanned(
+                    &option.path,
+                    "The `code_field` option requires the `serde_json` feature.",
+                ));
+            }
+
+            code_field = Some(literal.clone());
+        } else if option.path.is_ident("log_level") {
+            log_level = Some(tracing_level(literal)?);
+        } else if option.path.is_ident("with_display") {
+            with_display = Some(literal.clone());
+        } else if option.path.is_ident("error_key_case") {
+            // the only key named by the macro is `error`, its case follows the serde rule.
+            let key = match value.as_str() {
+                "lowercase" | "camelCase" | "snake_case" | "kebab-case" => "error",
+                "PascalCase" => "Error",
+                "UPPERCASE" | "SCREAMING_SNAKE_CASE" | "SCREAMING-KEBAB-CASE" => "ERROR",
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        literal,
+                        "Expected `error_key_case` to be a serde case such as \"camelCase\" or \"PascalCase\".",
+                    ));
+                }
+            };
+
+            error_key = Some((option.path.clone(), LitStr::new(key, literal.span())));
+        } else if option.path.is_ident("default_or_variant") {
+            default_or_variant = Some(parse_literal::<Ident>(
+                literal,
+                "Expected `default_or_variant` to be a variant name.",
+            )?);
+        } else if option.path.is_ident("variant_case") {
+            snake_case_variants = match value.as_str() {
+                "snake" => true,
+                "screaming_snake" => false,
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        literal,
+                        "Expected `variant_case` to be \"snake\" or \"screaming_snake\".",
+                    ));
+                }
+            };
+        } else {
+            return Err(syn::Error::new_spanned(&option.path, "Unknown `actix_error` option."));
+        }
+    }
+
+    if matches!(input.data, Data::Struct(_))
+        && default_status.is_none()
+        && !input.attrs.iter().any(|attr| attr.path().is_ident("http_status"))
+    {
+        return Err(syn::Error::new(
+            type_name.span(),
+            "ActixError on structs requires a `#[http_status(...)]` attribute on the struct \
+            or a `default_status`.",
+        ));
+    }
+
+    if let (Some(_), Some(async_transformer)) = (&transformer, &async_transformer) {
+        return Err(syn::Error::new_spanned(
+            async_transformer,
+            "The `transformer` and `async_transformer` options are exclusive.",
+        ));
+    }
+
+    if let (false, Some((option, _))) = (json_body, &error_key) {
+        return Err(syn::Error::new_spanned(
+            option,
+            "The `error_key_case` option requires the `json_body` flag.",
+        ));
+    }
+
+    let error_key = error_key.map_or_else(|| LitStr::new("error", Span::call_site().into()), |(_, key)| key);
+
+    if let (Some(flag), Some(_)) = (&skip_into_impl, &async_transformer) {
+        return Err(syn::Error::new_spanned(
+            flag,
+            "The `skip_into_impl` flag can't be used with an `async_transformer`, which has no `Into` implementation.",
+        ));
+    }
+
+    if let (true, Some(async_transformer)) = (impl_response_error, &async_transformer) {
+        return Err(syn::Error::new_spanned(
+            async_transformer,
+            "The `impl_response_error` flag can't be used with an `async_transformer`.",
+        ));
+    }
+
+    // the alternate format includes the source of the error, `expose_source = false` removes it.
+    let display_message = |expose_source: bool| {
+        let format = if expose_source { "{:#}" } else { "{}" };
+
+        if secure_display {
+            quote! {{
+                let message = format!(#format, self);
+
+                if cfg!(debug_assertions) {
+                    message
+                } else {
+                    match message.split_once(": ") {
+                        Some((safe, _)) => safe.to_string(),
+                        None => message,
+                    }
+                }
+            }}
+        } else {
+            quote! { format!(#format, self) }
+        }
+    };
+
+    let mut into_response_arms = Vec::new();
+    let mut into_error_arms = Vec::new();
+    let mut into_string_arms = Vec::new();
+
+    let definition_macro = describe.then(|| describe_macro(input, default_status_tokens.as_ref()));
+
+    let variant_names = describe.then(|| {
+        let names = units
+            .iter()
+            .map(|unit| unit.ident.to_string().trim_start_matches("r#").to_owned());
+
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                /// The names of the variants of this error, in declaration order.
+                pub const VARIANT_NAMES: &'static [&'static str] = &[#(#names),*];
+            }
+        }
+    });
+
+    // the sort is stable, so variants with the same
+    // priority keep their declaration order.
+    units.sort_by_key(|unit| Reverse(unit.options.priority));
+
+    let mut merge_impls = Vec::new();
+
+    for merged in &merge_from {
+        let wrapping = units.iter().find_map(|unit| {
+            let mut fields = unit.fields.iter();
+
+            match (fields.next(), fields.next()) {
+                (Some(field), None) if field.ty == *merged => Some((unit, false)),
+                _ => None,
+            }
+        });
+
+        let Some((unit, convert)) = wrapping.or_else(|| {
+            units
+                .iter()
+                .find(|unit| unit.options.catch_all)
+                .map(|unit| (unit, true))
+        }) else {
+            return Err(syn::Error::new_spanned(
+                merged,
+                "Merged errors require a variant holding them or a `catch_all` variant.",
+            ));
+        };
+
+        let unit_path = &unit.path;
+        let value = if convert {
+            quote! { ::core::convert::Into::into(error) }
+        } else {
+            quote! { error }
+        };
+
+        let construct = match unit.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => quote! { #unit_path(#value) },
+            Fields::Named(fields) if fields.named.len() == 1 => {
+                let field = &fields.named[0].ident;
+                quote! { #unit_path { #field: #value } }
+            }
+            fields => {
+                return Err(syn::Error::new_spanned(
+                    fields,
+                    "The variant merged errors are converted into must hold a single field.",
+                ));
+            }
+        };
+
+        merge_impls.push(quote! {
+            impl #impl_generics ::core::convert::From<#merged> for #type_name #type_generics #where_clause {
+                fn from(error: #merged) -> Self {
+                    #construct
+                }
+            }
+        });
+    }
+
+    let mut retriable_arms = Vec::new();
+    let mut status_arms = Vec::new();
+    let mut try_from_arms = Vec::new();
+    let mut log_arms = Vec::new();
+    let mut capture_arms = Vec::new();
+    let mut warnings = Vec::from_iter(default_status_warning);
+    let mut or_default_units = Vec::new();
+
+    for unit in &units {
+        let mut http_status = default_status
+            .clone()
+            .unwrap_or_else(|| HttpStatus::Named(Ident::new("InternalServerError", Span::call_site().into())));
+
+        let mut status_options = StatusOptions::default();
+
+        let status_attr = single_attr(unit.attrs, "http_status")?;
+
+        if let Some(attr) = status_attr {
+            (http_status, status_options) = HttpStatus::parse(attr)?;
+
+            // an empty success response is deliberate, such as a `204 No Content`.
+            if http_status.is_success() && !status_options.empty_body {
+                warnings.push(warning(attr, SUCCESS_STATUS_WARNING));
+            }
+        }
+
+        // without a named variant `#[or_default]` uses the first unit variant responding with a `400 Bad Request`.
+        let or_default_unit = match &default_or_variant {
+            Some(name) => unit.ident == name,
+            None => matches!(unit.fields, Fields::Unit) && http_status.code() == Some(400),
+        };
+
+        if or_default_unit {
+            or_default_units.push(unit);
+        }
+
+        let mut unit_transformer = None;
+
+        if let Some(attr) = single_attr(unit.attrs, "http_transformer")? {
+            let Meta::NameValue(MetaNameValue {
+                value: Expr::Lit(ExprLit {
+                    lit: Lit::Str(name),
+                    ..
+                }),
+                ..
+            }) = &attr.meta
+            else {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "Expected `#[http_transformer = \"fn_name\"]`.",
+                ));
+            };
+
+            unit_transformer = Some(name.parse::<Path>()?);
+        }
+
+        let mut unit_expose_source = expose_source;
+
+        // the variant setting overrides the `expose_source` option of the enum.
+        if let Some(attr) = single_attr(unit.attrs, "http_expose_source")? {
+            unit_expose_source = attr
+                .parse_args::<LitBool>()
+                .map_err(|_| syn::Error::new_spanned(attr, "Expected `#[http_expose_source(true)]`."))?
+                .value;
+        }
+
+        // a static message replaces the formatted error in the response.
+        let display_message = match &status_options.message {
+            Some(message) => quote! { ::std::string::ToString::to_string(#message) },
+            None => display_message(unit_expose_source),
+        };
+
+        // the variant name is prefixed to the message of every body built from it.
+        let display_message = if include_variant_name {
+            let name = snake_case(&unit.ident.to_string());
+            let name = if snake_case_variants { name } else { name.to_uppercase() };
+
+            quote! { format!("[{}] {}", #name, #display_message) }
+        } else {
+            display_message
+        };
+
+        let mut error_code = None;
+
+        if let Some(attr) = single_attr(unit.attrs, "error_code")? {
+            let code = attr
+                .parse_args::<LitInt>()
+                .map_err(|_| syn::Error::new_spanned(attr, "Expected `#[error_code(number)]`."))?;
+
+            if code_field.is_none() {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "The `error_code` attribute requires the `code_field` option in `actix_error`.",
+                ));
+            }
+
+            error_code = Some(code);
+        }
+
+        // the code is added next to the message of the json bodies built by the macro.
+        let code_entry = error_code.map(|code| quote! { , #code_field: #code });
+
+        let mut json_message = json_body.then(|| {
+            quote! { serde_json::json!({ #error_key: #display_message #code_entry }).to_string() }
+        });
+
+        // the body of a variant overrides the `json_body` flag of the enum.
+        if let Some(attr) = single_attr(unit.attrs, "http_json_body")? {
+            if !cfg!(feature = "serde_json") {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "The `http_json_body` attribute requires the `serde_json` feature.",
+                ));
+            }
+
+            let options = attr.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+
+            // both options build the whole body, so only one of them can be set.
+            if let Some(option) = options.iter().nth(1) {
+                return Err(syn::Error::new_spanned(
+                    &option.path,
+                    "The `http_json_body` attribute takes a single `key` or `object` option.",
+                ));
+            }
+
+            for option in options {
+                let Expr::Lit(ExprLit {
+                    lit: Lit::Str(value),
+                    ..
+                }) = &option.value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &option.value,
+                        "Expected a string literal value.",
+                    ));
+                };
+
+                if option.path.is_ident("key") {
+                    json_message = Some(quote! {
+                        serde_json::json!({ #value: #display_message #code_entry }).to_string()
+                    });
+                } else if option.path.is_ident("object") {
+                    let object = value.parse::<Path>()?;
+
+                    json_message = Some(quote! {
+                        serde_json::Value::to_string(&#object(&self))
+                    });
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        &option.path,
+                        "Expected `key` or `object`.",
+                    ));
+                }
+            }
+        }
+
+        // the transformer of a variant overrides the one of the enum.
+        let awaited = unit_transformer.is_none() && async_transformer.is_some();
+        let unit_transformer = unit_transformer
+            .as_ref()
+            .or(transformer.as_ref())
+            .or(async_transformer.as_ref());
+
+        let response_builder = http_status.response_builder();
+
+        let pattern = unit.pattern();
+
+        let is_retriable = retriable || unit.options.retriable;
+        retriable_arms.push(quote! { #pattern => #is_retriable });
+
+        let transparent = unit.attrs.iter().find(|attr| attr.path().is_ident("actix_transparent"));
+        let delegate_to = unit.options.delegate_to.as_ref();
+
+        if let (Some(attr), Some(_)) = (transparent, delegate_to) {
+            return Err(syn::Error::new_spanned(
+                attr,
+                "The `actix_transparent` attribute and the `delegate_to` option are exclusive.",
+            ));
+        }
+
+        // transparent units forward the conversion to the error they hold,
+        // delegated ones do the same naming the type of that error.
+        let forward = match (transparent, delegate_to) {
+            (Some(attr), _) => Some((attr.to_token_stream(), "The `actix_transparent` attribute")),
+            (None, Some(delegate)) => Some((delegate.to_token_stream(), "The `delegate_to` option")),
+            (None, None) => None,
+        };
+
+        if let Some((origin, name)) = forward {
+            let Some((inner_pattern, field)) = unit.inner_pattern() else {
+                return Err(syn::Error::new_spanned(origin, format!("{name} requires a single field.")));
+            };
+
+            // the status comes from the inner error, a status on the variant would be ignored.
+            if let Some(status_attr) = status_attr {
+                return Err(syn::Error::new_spanned(
+                    status_attr,
+                    format!("{name} forwards the status of the inner error, it can't be used with `http_status`."),
+                ));
+            }
+
+            let inner_type = match delegate_to {
+                Some(delegate) => delegate.to_token_stream(),
+                None => field.ty.to_token_stream(),
+            };
+
+            // a `ResponseError` is built from a reference, so the inner error must be one too.
+            let (into_response, status) = if impl_response_error {
+                (
+                    quote_spanned! { inner_type.span() =>
+                        <#inner_type as actix_web::ResponseError>::error_response(&inner)
+                    },
+                    quote_spanned! { inner_type.span() =>
+                        <#inner_type as actix_web::ResponseError>::status_code(&inner)
+                    },
+                )
+            } else {
+                let into_response = match delegate_to {
+                    Some(delegate) => quote_spanned! { delegate.span() =>
+                        <#delegate as ::core::convert::Into<actix_web::HttpResponse>>::into(inner)
+                    },
+                    None => quote_spanned! { field.ty.span() =>
+                        ::core::convert::Into::<actix_web::HttpResponse>::into(inner)
+                    },
+                };
+
+                (into_response, quote! { inner.status() })
+            };
+
+            into_response_arms.push(quote! { #inner_pattern => #into_response });
+            status_arms.push(quote! { #inner_pattern => #status });
+            // the inner error only has to convert into a response, so its body is the
+            // message, or its status when the body can't be read without polling.
+            into_string_arms.push(quote! {
+                #inner_pattern => {
+                    let response = #into_response;
+                    let status = response.status();
+
+                    match actix_web::body::MessageBody::try_into_bytes(response.into_body()) {
+                        Ok(bytes) => ::std::string::String::from_utf8_lossy(&bytes).into_owned(),
+                        Err(_) => ::std::string::ToString::to_string(&status),
+                    }
+                }
+            });
+            into_error_arms.push(quote! {
+                #inner_pattern => {
+                    let (response, body) = #into_response.into_parts();
+
+                    let (message, body) = match actix_web::body::MessageBody::try_into_bytes(body) {
+                        Ok(bytes) => (
+                            ::std::string::String::from_utf8_lossy(&bytes).into_owned(),
+                            actix_web::body::BoxBody::new(bytes),
+                        ),
+                        Err(body) => (::std::string::ToString::to_string(&response.status()), body),
+                    };
+
+                    actix_web::error::InternalError::from_response(message, response.set_body(body)).into()
+                }
+            });
+
+            continue;
+        }
+
+        let mut unit_log_level = log_level.clone();
+
+        // the level of a variant overrides the `log_level` option of the enum.
+        if let Some(attr) = single_attr(unit.attrs, "log")? {
+            let Ok(MetaNameValue {
+                path,
+                value: Expr::Lit(ExprLit {
+                    lit: Lit::Str(level),
+                    ..
+                }),
+                ..
+            }) = attr.parse_args::<MetaNameValue>()
+            else {
+                return Err(syn::Error::new_spanned(attr, "Expected `#[log(level = \"warn\")]`."));
+            };
+
+            if !path.is_ident("level") {
+                return Err(syn::Error::new_spanned(path, "Expected `#[log(level = \"warn\")]`."));
+            }
+
+            unit_log_level = Some(tracing_level(&level)?);
+        }
+
+        // the levels are checked without the `tracing` feature, but no event is emitted.
+        if let (true, Some(level)) = (cfg!(feature = "tracing"), unit_log_level) {
+            log_arms.push(quote! {
+                #pattern => tracing::event!(tracing::Level::#level, error = %self, "HTTP error response")
+            });
+        }
+
+        for attr in unit.attrs.iter().filter(|attr| attr.path().is_ident("capture_to_sentry")) {
+            attr.meta
+                .require_path_only()
+                .map_err(|_| syn::Error::new_spanned(attr, "The `capture_to_sentry` attribute doesn't take arguments."))?;
+
+            // the feature is checked here, so the user crate only needs `sentry` when it's enabled.
+            if cfg!(feature = "sentry") {
+                capture_arms.push(quote! {
+                    #pattern => {
+                        let error: &Self = &self;
+                        sentry::capture_error(error);
+                    }
+                });
+            }
+        }
+
+        let mut headers = Vec::new();
+
+        // the body of a `body_fn` isn't json even if `json_body` is set.
+        let mut content_type = (json_message.is_some() && status_options.body_fn.is_none())
+            .then(|| LitStr::new("application/json", Span::call_site().into()));
+
+        // the content type of a variant overrides the one of `json_body`.
+        if let Some(attr) = single_attr(unit.attrs, "http_content_type")? {
+            let mime = attr.parse_args::<LitStr>().map_err(|_| {
+                syn::Error::new_spanned(attr, "Expected `#[http_content_type(\"type/subtype\")]`.")
+            })?;
+
+            if !mime.value().contains('/') {
+                warnings.push(warning(&mime, "The `http_content_type` doesn't look like a MIME type."));
+            }
+
+            content_type = Some(mime);
+        }
+
+        if let Some(content_type) = content_type {
+            headers.push(quote! { (actix_web::http::header::CONTENT_TYPE, #content_type) });
+        }
+
+        // the body of a `body_fn` is sent as it's built, so it isn't encoded.
+        if base64_body && status_options.body_fn.is_none() {
+            headers.push(quote! { ("Content-Transfer-Encoding", "base64") });
+        }
+
+        for attr in unit.attrs.iter().filter(|attr| attr.path().is_ident("http_header")) {
+            let header = attr
+                .parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)
+                .ok()
+                .filter(|header| header.len() == 2);
+
+            let Some(header) = header else {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "Expected `#[http_header(\"Name\", \"Value\")]`.",
+                ));
+            };
+
+            let (name, value) = (&header[0], &header[1]);
+            headers.push(quote! { (#name, #value) });
+        }
+
+        if let Some(attr) = single_attr(unit.attrs, "http_location")? {
+            let location = attr
+                .parse_args::<LitStr>()
+                .map_err(|_| syn::Error::new_spanned(attr, "Expected `#[http_location(\"/path\")]`."))?;
+
+            if http_status.code().is_some_and(|code| !(300..=399).contains(&code)) {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "The `http_location` attribute requires a 3xx `http_status`.",
+                ));
+            }
+
+            headers.push(quote! { (actix_web::http::header::LOCATION, #location) });
+        }
+
+        if let Some(attr) = single_attr(unit.attrs, "http_retry_after")? {
+            let seconds = attr
+                .parse_args::<LitInt>()
+                .and_then(|seconds| seconds.base10_parse::<u64>())
+                .map_err(|_| {
+                    syn::Error::new_spanned(attr, "Expected `#[http_retry_after(seconds)]`.")
+                })?
+                .to_string();
+
+            headers.push(quote! { (actix_web::http::header::RETRY_AFTER, #seconds) });
+        }
+
+        // the builder is passed by value to transformers, so the headers
+        // are inserted on a binding instead of chaining `insert_header`.
+        let response_code = if headers.is_empty() {
+            response_builder
+        } else {
+            quote! {({
+                let mut builder = #response_builder;
+                #(builder.insert_header(#headers);)*
+                builder
+            })}
+        };
+
+        let response_message = json_message.unwrap_or_else(|| display_message.clone());
+
+        let response_message = if base64_body {
+            quote! {
+                base64::Engine::encode(
+                    &base64::engine::general_purpose::STANDARD,
+                    #response_message
+                )
+            }
+        } else {
+            response_message
+        };
+
+        // empty bodies skip the message, transformers get an empty string.
+        let response_message = if status_options.empty_body {
+            quote! { ::std::string::String::new() }
+        } else {
+            response_message
+        };
+
+        if let (Some(body_fn), Some(_)) = (&status_options.body_fn, unit_transformer) {
+            return Err(syn::Error::new_spanned(
+                body_fn,
+                "The `body_fn` modifier can't be used with a transformer.",
+            ));
+        }
+
+        // the body function borrows the error, which is still owned by the match.
+        let body_fn = status_options.body_fn.as_ref().map(|body_fn| {
+            quote_spanned! { body_fn.span() => #body_fn(&self) }
+        });
+
+        // bodies that can't be read without polling, such as streams, fall back to the message.
+        into_string_arms.push(match &body_fn {
+            Some(body_fn) => quote! {
+                #pattern => match actix_web::body::MessageBody::try_into_bytes(#body_fn) {
+                    Ok(bytes) => ::std::string::String::from_utf8_lossy(&bytes).into_owned(),
+                    Err(_) => #display_message,
+                }
+            },
+            None => quote! { #pattern => #display_message },
+        });
+
+        into_response_arms.push(match unit_transformer {
+            _ if body_fn.is_some() => quote! { #pattern => #response_code.body(#body_fn) },
+            Some(tr) => {
+                let call = transformer_call(tr, awaited, &response_code, &response_message);
+                quote! { #pattern => #call }
+            }
+            None if status_options.empty_body => quote! { #pattern => #response_code.finish() },
+            None => quote! { #pattern => #response_code.body(#response_message) },
+        });
+
+        let status_code = http_status.status_code();
+        status_arms.push(quote! { #pattern => #status_code });
+
+        if impl_try_from_status {
+            let path = &unit.path;
+
+            let construction = match unit.fields {
+                Fields::Unit => quote! { #path },
+                Fields::Unnamed(fields) => {
+                    let defaults = fields.unnamed.iter().map(|field| {
+                        quote_spanned! { field.ty.span() => ::core::default::Default::default() }
+                    });
+
+                    quote! { #path(#(#defaults),*) }
+                }
+                Fields::Named(fields) => {
+                    return Err(syn::Error::new_spanned(
+                        fields,
+                        "Variants with named fields can't be built by `impl_try_from_status`.",
+                    ));
+                }
+            };
+
+            // a path status is only known when the code runs.
+            let known_code = match http_status.code() {
+                Some(known) => quote! { #known },
+                None => quote! { actix_web::http::StatusCode::as_u16(&#status_code) },
+            };
+
+            try_from_arms.push(quote! {
+                if code == #known_code {
+                    return ::core::result::Result::Ok(#construction);
+                }
+            });
+        }
+
+        let error = match body_fn {
+            Some(body) => quote! {{
+                let response = #response_code.body(#body);
+                actix_web::error::InternalError::from_response(#display_message, response).into()
+            }},
+            None => http_status.error(&display_message),
+        };
+
+        into_error_arms.push(quote! { #pattern => #error });
+    }
+
+    // the units are sorted by priority, as for a shared status in `TryFrom`.
+    let or_default = match (or_default_units.into_iter().next(), &default_or_variant) {
+        (Some(unit), _) if matches!(unit.fields, Fields::Unit) => {
+            let path = &unit.path;
+
+            quote! {
+                impl #impl_generics #type_name #type_generics #where_clause {
+                    #[doc(hidden)]
+                    pub fn __or_default() -> Self {
+                        #path
+                    }
+                }
+            }
+        }
+        (Some(_), Some(name)) => {
+            return Err(syn::Error::new_spanned(
+                name,
+                format!("The `{name}` variant can't be built by `#[or_default]`, it must be a unit variant."),
+            ));
+        }
+        (None, Some(name)) => {
+            return Err(syn::Error::new_spanned(name, format!("The `{name}` variant doesn't exist.")));
+        }
+        // without a default error there is no `__or_default`, so a route using it doesn't compile.
+        _ => quote! {},
+    };
+
+    // an enum without variants can't be built, but a match on a reference
+    // to it still needs an arm, so every match gets an unreachable one.
+    if units.is_empty() {
+        let unreachable = quote! {
+            #[allow(unreachable_patterns)]
+            _ => ::core::unreachable!()
+        };
+
+        for arms in [
+            &mut into_response_arms,
+            &mut status_arms,
+            &mut retriable_arms,
+            &mut into_error_arms,
+            &mut into_string_arms,
+        ] {
+            arms.push(unreachable.clone());
+        }
+    } else if input.attrs.iter().any(|attr| attr.path().is_ident("non_exhaustive")) {
+        // the variants of a `non_exhaustive` enum may grow, the unknown ones are server errors.
+        into_response_arms.push(quote! {
+            #[allow(unreachable_patterns)]
+            _ => actix_web::HttpResponse::InternalServerError().body("unknown error")
+        });
+        status_arms.push(quote! {
+            #[allow(unreachable_patterns)]
+            _ => actix_web::http::StatusCode::INTERNAL_SERVER_ERROR
+        });
+        retriable_arms.push(quote! {
+            #[allow(unreachable_patterns)]
+            _ => false
+        });
+        into_error_arms.push(quote! {
+            #[allow(unreachable_patterns)]
+            _ => actix_web::error::ErrorInternalServerError("unknown error")
+        });
+        into_string_arms.push(quote! {
+            #[allow(unreachable_patterns)]
+            _ => ::std::string::ToString::to_string("unknown error")
+        });
+    }
+
+    // transparent units aren't logged, the error they hold is.
+    let log_event = (!log_arms.is_empty()).then(|| {
+        quote! {
+            match &self {
+                #(#log_arms,)*
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        }
+    });
+
+    let capture_event = (!capture_arms.is_empty()).then(|| {
+        quote! {
+            match &self {
+                #(#capture_arms,)*
+                #[allow(unreachable_patterns)]
+                _ => {}
+            }
+        }
+    });
+
+    let emit_event_ref = emit_event.as_ref().map(|event| {
+        quote! { #event::emit(::core::clone::Clone::clone(self)); }
+    });
+
+    let emit_event = emit_event.map(|event| {
+        quote! { #event::emit(::core::clone::Clone::clone(&self)); }
+    });
+
+    let into_response = if http3_compatible {
+        quote! {
+            let mut response = match self {
+                #(#into_response_arms),*
+            };
+
+            // connection specific headers are forbidden in HTTP/3.
+            for header in ["connection", "keep-alive", "proxy-connection", "transfer-encoding", "upgrade"] {
+                response.headers_mut().remove(header);
+            }
+
+            response
+        }
+    } else {
+        quote! {
+            match self {
+                #(#into_response_arms),*
+            }
+        }
+    };
+
+    // the backtrace is always captured in debug builds, release builds follow `RUST_BACKTRACE`.
+    let into_response = if backtrace {
+        quote! {
+            let mut response = { #into_response };
+
+            #[cfg(debug_assertions)]
+            let backtrace = ::std::backtrace::Backtrace::force_capture();
+            #[cfg(not(debug_assertions))]
+            let backtrace = ::std::backtrace::Backtrace::capture();
+
+            // a header can't hold line breaks, so the frames are joined in a single line,
+            // which is cut at 4kB to stay under the header limits of common proxies.
+            if backtrace.status() == ::std::backtrace::BacktraceStatus::Captured {
+                let mut frames = ::std::string::String::new();
+
+                for frame in backtrace.to_string().lines().map(str::trim) {
+                    if frames.len() + frame.len() > 4090 {
+                        frames.push_str(" | ...");
+                        break;
+                    }
+
+                    if !frames.is_empty() {
+                        frames.push_str(" | ");
+                    }
+
+                    frames.push_str(frame);
+                }
+
+                if let Ok(value) = actix_web::http::header::HeaderValue::from_str(&frames) {
+                    response
+                        .headers_mut()
+                        .insert(actix_web::http::header::HeaderName::from_static("x-backtrace"), value);
+                }
+            }
+
+            response
+        }
+    } else {
+        into_response
+    };
+
+    let retriable_impl = if retriable || units.iter().any(|unit| unit.options.retriable) {
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                /// Whether clients may retry the request that failed with this error.
+                pub fn is_retriable(&self) -> bool {
+                    match self {
+                        #(#retriable_arms),*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let status_fn = if with_status_fn {
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                /// The status code of the response this error converts into.
+                pub fn status(&self) -> actix_web::http::StatusCode {
+                    match self {
+                        #(#status_arms),*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // the status checks use `status` when it's generated, otherwise the match is inlined.
+    let status = if with_status_fn {
+        quote! { self.status() }
+    } else {
+        quote! {
+            match self {
+                #(#status_arms),*
+            }
+        }
+    };
+
+    let is_client_error = with_is_client_error.then(|| {
+        quote! {
+            /// Whether the response this error converts into has a 4xx status.
+            pub fn is_client_error(&self) -> bool {
+                actix_web::http::StatusCode::is_client_error(&#status)
+            }
+        }
+    });
+
+    let is_server_error = with_is_server_error.then(|| {
+        quote! {
+            /// Whether the response this error converts into has a 5xx status.
+            pub fn is_server_error(&self) -> bool {
+                actix_web::http::StatusCode::is_server_error(&#status)
+            }
+        }
+    });
+
+    let status_checks = if with_is_client_error || with_is_server_error {
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                #is_client_error
+                #is_server_error
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // units are sorted by priority, so the variant with the highest
+    // priority, or the first declared one, wins a shared status.
+    let try_from_status = if impl_try_from_status {
+        quote! {
+            impl #impl_generics ::core::convert::TryFrom<u16> for #type_name #type_generics #where_clause {
+                type Error = ();
+
+                fn try_from(code: u16) -> ::core::result::Result<Self, ()> {
+                    #(#try_from_arms)*
+                    ::core::result::Result::Err(())
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // the template captures its arguments by name, such as `{self:?}`.
+    let display = if let Some(template) = with_display {
+        quote! {
+            impl #impl_generics ::core::fmt::Display for #type_name #type_generics #where_clause {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::write!(f, #template)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // the string is the message of the response, or the body built by `body_fn`.
+    let into_string = if impl_into_string {
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                #[doc(hidden)]
+                fn __into_string(self) -> ::std::string::String {
+                    match self {
+                        #(#into_string_arms),*
+                    }
+                }
+            }
+
+            impl #impl_generics ::core::convert::From<#type_name #type_generics> for ::std::string::String
+            #where_clause
+            {
+                fn from(error: #type_name #type_generics) -> Self {
+                    error.__into_string()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    // actix_web implements `From<impl ResponseError> for actix_web::Error`,
+    // which replaces the `Into<actix_web::Error>` implementation.
+    let into_error = if impl_response_error {
+        quote! {
+            impl #impl_generics actix_web::ResponseError for #type_name #type_generics #where_clause {
+                fn status_code(&self) -> actix_web::http::StatusCode {
+                    match self {
+                        #(#status_arms),*
+                    }
+                }
+
+                fn error_response(&self) -> actix_web::HttpResponse {
+                    #log_event
+                    #capture_event
+                    #emit_event_ref
+                    #into_response
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics ::core::convert::Into<actix_web::Error> for #type_name #type_generics #where_clause {
+                fn into(self) -> actix_web::Error {
+                    #emit_event
+                    match self {
+                        #(#into_error_arms),*
+                    }
+                }
+            }
+        }
+    };
+
+    // an async transformer can't be awaited from `Into`, so the conversion
+    // is only available as `async_into` and `.into()` doesn't compile.
+    let into_response = if skip_into_impl.is_some() {
+        // the conversion is implemented by hand.
+        quote! {}
+    } else if async_transformer.is_some() {
+        quote! {
+            impl #impl_generics #type_name #type_generics #where_clause {
+                /// Converts the error into a response awaiting the transformer.
+                pub async fn async_into(self) -> actix_web::HttpResponse {
+                    #log_event
+                    #capture_event
+                    #emit_event
+                    #into_response
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics ::core::convert::Into<actix_web::HttpResponse> for #type_name #type_generics #where_clause {
+                fn into(self) -> actix_web::HttpResponse {
+                    #log_event
+                    #capture_event
+                    #emit_event
+                    #into_response
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #into_response
+
+        #into_error
+
+        #display
+
+        #into_string
+
+        #(#merge_impls)*
+
+        #retriable_impl
+
+        #status_fn
+
+        #variant_names
+
+        #status_checks
+
+        #try_from_status
+
+        #or_default
+
+        #definition_macro
+
+        #(#warnings)*
+    })
+}
+
+/// Generates a hidden macro named as the error with a `__actix_error_describe_` prefix,
+/// which calls the macro it's given with the definition of the error, so `unify_errors!`
+/// can read its variants. The variants without a status get the `default_status` one,
+/// since the options aren't copied.
+fn describe_macro(input: &DeriveInput, default_status: Option<&TokenStream2>) -> TokenStream2 {
+    let type_name = &input.ident;
+    let generics = &input.generics;
+    let where_clause = &generics.where_clause;
+    let name = describe_name(type_name);
+
+    let definition = match &input.data {
+        Data::Enum(data_enum) => {
+            let variants = data_enum.variants.iter().map(|variant| {
+                let mut variant = variant.clone();
+                variant.discriminant = None;
+
+                // transparent variants take the status of the inner error.
+                let has_status = variant.attrs.iter().any(|attr| {
+                    attr.path().is_ident("http_status") || attr.path().is_ident("actix_transparent")
+                }) || variant_options(&variant).is_ok_and(|options| options.delegate_to.is_some());
+
+                if let (false, Some(status)) = (has_status, default_status) {
+                    variant.attrs.push(parse_quote! { #[http_status(#status)] });
+                }
+
+                variant
+            });
+
+            quote! { enum #type_name #generics #where_clause { #(#variants),* } }
+        }
+        _ => quote! { struct #type_name #generics #where_clause; },
+    };
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(unused_macros)]
+        macro_rules! #name {
+            ($($callback:ident)::+ { $($state:tt)* }) => {
+                $($callback)::+! { $($state)* { #definition } }
+            };
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #name;
+    }
+}
+
+/// The name of the macro `describe_macro` declares for an error.
+fn describe_name(type_name: &Ident) -> Ident {
+    quote::format_ident!("__actix_error_describe_{}", type_name.to_string().trim_start_matches("r#"))
+}
+
+/// This macro attribute wraps actix http route handlers, due to
+/// the limitation where the attribute definition order is undefined
+/// this macro also wraps the actix_web::{get, post, put, patch, delete, options, trace, head}
+/// macros.
+///
+/// The usage in a route handler is the following.
+///
+/// ```ignore
+/// use actix_error_proc::{ActixError, Error, HttpResult}; // Error is a thiserror re export.
+/// use crate::models::user::User;
+/// use actix_web::{main, App, HttpServer}
+/// use serde_json::{from_slice, Error as JsonError};
+/// use std::io::Error as IoError;
+///
+/// // assuming we have a wrapped enum
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The body could not be parsed.")]
+///     #[status_code(BadRequest)]
+///     InvalidBody(#[from] JsonError)
+/// }
+///
+/// #[proof_route(post("/users"))]
+/// async fn some_route(body: Bytes) -> HttpResult<SomeError> {
+///     let user: User = from_slice(body)?; // notice the use of `?`.
+///
+///     // Do something with the user.
+///
+///     Ok(HttpResponse::NoContent()) // return Ok if everything went fine.
+/// }
+///
+/// async fn main() -> IoError {
+///     HttpServer::new(|| {
+///         App::new()
+///             .service(some_route) // we can register the route normally.
+///     })
+///         .bind(("0.0.0.0", 8080))?
+///         .run()
+///         .await
+/// }
+/// ```
+///
+/// There is an extra attribute we can add to route collectors to override
+/// it's error status code, in the case we don't want the original status code
+/// or we didn't create the collector and the original error does not match our
+/// expectations we can use `#[or]`, which lets us specify an error branch
+/// from any type instance that implements `Into<HttpResponse>`.
+///
+/// ```ignore
+/// #[proof_route(post("/"))]
+/// async fn route(#[or(SomeError::InvalidUser)] user: Json<User>) // ...
+/// ```
+///
+/// In this case if `Json<User>` fails while collecting from the http request
+/// whatever `<SomeError::InvalidUser as Into<actix_web::HttpResponse>>.into()` returns
+/// will be passed directly as a response for the route.
+///
+/// Any extractor works with `#[or]`, such as `Json`, `Form`, `Query`, `Path` and
+/// typed headers with `Header<T>`, since they are all collected with `FromRequest`,
+/// so any type implementing it can be a route parameter. The parameters are collected in order
+/// and the route responds as soon as one of them fails, so the collectors after it aren't
+/// called, which matters for the ones with side effects such as reading the body.
+///
+/// If you don't add the attribute, the request will be collected as normal and in the
+/// case of any error the original error implementation for that collector will
+/// be applied.
+///
+/// The `#[or_map(...)]` attribute builds the error from the one returned by the collector,
+/// it takes a function or closure receiving that error, `actix_web::Error` for most
+/// extractors, and returning anything that implements `Into<HttpResponse>`. It can't
+/// be used together with `#[or]`.
+///
+/// ```ignore
+/// #[proof_route(post("/users"))]
+/// async fn create_user(
+///     #[or_map(|err| SomeError::InvalidUser(err.to_string()))] user: Json<User>
+/// ) -> HttpResult<SomeError> {
+///     // ...
+/// }
+/// ```
+///
+/// The `#[or_default]` attribute responds with the default error of the enum the route
+/// returns, so the route must return an `HttpResult` of it. That error is the unit variant
+/// with a `400 Bad Request` status and the highest `priority`, the first declared one among
+/// equal priorities, or the unit variant named with the
+/// `default_or_variant` option of the enum, a route using it with an enum without
+/// either of them doesn't compile.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// #[actix_error(default_or_variant = "Malformed")]
+/// enum SomeError {
+///     #[error("The request is malformed.")]
+///     #[http_status(UnprocessableEntity)]
+///     Malformed,
+///     // ...
+/// }
+///
+/// #[proof_route(get("/users/{id}"))]
+/// async fn get_user(#[or_default] id: Path<u32>) -> HttpResult<SomeError> {
+///     // ...
+/// }
+/// ```
+///
+/// The `Payload` extractor never fails, since it only hands over the body stream and
+/// the errors come when reading it, so when it's written as `web::Payload` or
+/// `actix_web::web::Payload` it's bound without an error arm and doesn't need `#[or]`.
+/// A `Payload` imported by name goes through the usual extraction, since it may be
+/// another extractor.
+///
+/// Unlike `Payload`, the `Bytes` and `String` extractors buffer the body and fail when it's
+/// bigger than the configured limit, `256kB` by default, so without `#[or]` such a body
+/// responds with actix_web's `413 Payload Too Large`.
+///
+/// `Data<T>` parameters, read from the app data, and `ReqData<T>` parameters, usually
+/// inserted by a middleware such as an authentication one, go through the same extraction,
+/// when the data is missing actix_web's own error responds with a `500 Internal Server Error`,
+/// so `#[or]` is only needed for another response.
+///
+/// ```ignore
+/// #[proof_route(get("/users"))]
+/// async fn list_users(pool: Data<DbPool>) -> HttpResult<SomeError> {
+///     // ...
+/// }
+/// ```
+///
+/// The `#[optional]` attribute makes a parameter an `Option` of its extractor, which is
+/// `None` when the extraction fails for any reason instead of responding with an error,
+/// it can't be used with `#[or]`.
+///
+/// ```ignore
+/// #[proof_route(post("/users"))]
+/// async fn route(#[optional] user: Json<User>) -> HttpResult<SomeError> {
+///     let user: Option<Json<User>> = user;
+///     // ...
+/// }
+/// ```
+///
+/// The `inject_request` option makes the `HttpRequest` available in the body of the
+/// route under the given name, without adding it to the parameters.
+///
+/// ```ignore
+/// #[proof_route(get("/users"), inject_request = "req")]
+/// async fn list_users() -> HttpResult<SomeError> {
+///     let token = req.headers().get("Authorization");
+///     // ...
+/// }
+/// ```
+///
+/// Routes that can't fail can also return an `HttpResponse` or an `impl Responder`,
+/// which is sent as it is.
+///
+/// ```ignore
+/// #[proof_route(get("/health"))]
+/// async fn health() -> HttpResponse {
+///     HttpResponse::Ok().finish()
+/// }
+/// ```
+///
+/// The `catch_panic` flag makes a panic inside the route respond with a
+/// `500 Internal Server Error` and an `internal error` body, instead of
+/// tearing down the worker that was serving the request, with the `tracing` feature
+/// enabled the message of the panic is logged as an error.
+///
+/// ```ignore
+/// #[proof_route(get("/report"), catch_panic)]
+/// async fn report() -> HttpResult<SomeError> {
+///     // a panic here responds with a 500.
+/// }
+/// ```
+///
+/// A route can answer to more than one method by listing them, as long as all
+/// of them share the same path.
+///
+/// ```ignore
+/// #[proof_route(get("/users"), head("/users"))]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+/// ```
+///
+/// The `any` method registers the route without a method guard, so it answers to every
+/// method, such as for CORS preflights or catch all endpoints, and it can't be listed with
+/// other methods. The `openapi` and `export_schema` records list the standard methods for it.
+///
+/// ```ignore
+/// #[proof_route(any("/proxy"))]
+/// async fn proxy() -> HttpResult<SomeError> // ...
+/// ```
+///
+/// The `route` method lists the methods of the route in its `methods` argument, separated
+/// by spaces or commas, a method can't be listed more than once.
+///
+/// ```ignore
+/// #[proof_route(route("/health", methods = "GET HEAD"))]
+/// async fn health() -> HttpResult<SomeError> // ...
+/// ```
+///
+/// After the methods you can add options as a comma separated list of `name = value`.
+///
+/// The `response_schema` option documents which type the route responds with on
+/// success, it adds a `Returns: TypeName as JSON` doc comment on the route and a
+/// `RESPONSE_SCHEMA` constant holding the `TypeId` of that type, the type must be `'static`.
+///
+/// ```ignore
+/// #[proof_route(get("/users"), response_schema = "Vec<User>")]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+///
+/// assert_eq!(list_users::RESPONSE_SCHEMA, TypeId::of::<Vec<User>>());
+/// ```
+///
+/// Flags can be set by their name only or with a boolean, such as `content_negotiation`
+/// which reads the `Accept` header before the route runs and makes an
+/// `actix_error_proc::ResponseFormat` available as `web::ReqData<ResponseFormat>`,
+/// JSON is picked for `*/*`, `application/*` and `application/json`, XML for
+/// `application/xml` and `text/xml`, types with `q=0` are never picked and a missing
+/// or malformed header picks JSON, if the client doesn't accept any supported format a
+/// `406 Not Acceptable` response is returned without running the route.
+///
+/// ```ignore
+/// #[proof_route(get("/users"), content_negotiation)]
+/// async fn list_users(format: ReqData<ResponseFormat>) -> HttpResult<SomeError> {
+///     match *format {
+///         ResponseFormat::Json => // ...
+///         ResponseFormat::Xml => // ...
+///     }
+/// }
+/// ```
+///
+/// The `pre_hook` option runs a function before anything is extracted from the request,
+/// the function must look like `fn(&HttpRequest) -> Result<(), impl Into<HttpResponse>>`
+/// and if it returns an error that error is the response of the route. To run
+/// more than one function use `pre_hooks`, which runs them in order.
+///
+/// ```ignore
+/// fn require_auth(req: &HttpRequest) -> Result<(), SomeError> {
+///     match req.headers().contains_key("Authorization") {
+///         true => Ok(()),
+///         false => Err(SomeError::Unauthorized)
+///     }
+/// }
+///
+/// #[proof_route(get("/users"), pre_hooks = ["require_auth", "rate_limit"])]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+/// ```
+///
+/// The `timeout_ms` option stops waiting for the route after that many milliseconds
+/// and responds with the `timeout_error` expression instead, which must implement
+/// `Into<HttpResponse>`, or with a `504 Gateway Timeout` and a `request timed out`
+/// body when there is no `timeout_error`.
+///
+/// ```ignore
+/// #[proof_route(get("/users"), timeout_ms = 5000, timeout_error = "SomeError::RequestTimeout")]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+/// ```
+///
+/// With the `tracing` feature enabled the `log_request` flag logs an `info` event when the
+/// route receives a request, with its method, path and remote address, and another one
+/// when it responds, with the status and the time it took.
+///
+/// ```ignore
+/// #[proof_route(get("/users"), log_request)]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+/// ```
+///
+/// The `compress_threshold` option only lets responses with a body bigger than that
+/// many bytes be compressed, smaller responses get a `Content-Encoding: identity`
+/// header which the `actix_web::middleware::Compress` middleware leaves untouched,
+/// so the middleware must still be registered in the app.
+///
+/// ```ignore
+/// #[proof_route(get("/users"), compress_threshold = 1024)]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+/// ```
+///
+/// The `retry_budget` option limits how many times a client can retry a request that
+/// failed with a retriable error, the type must implement `actix_error_proc::RetryBudget`
+/// and be registered as `web::Data` in the app. When the route fails with an error
+/// whose variant is marked with the `retriable` flag, the budget of the client IP
+/// is consumed and once it's exhausted the response is `429 Too Many Requests`. The
+/// error must have a retriable variant, without one it has no `is_retriable` method and
+/// the option doesn't compile.
+///
+/// ```ignore
+/// #[derive(ActixError, Error, Debug)]
+/// enum SomeError {
+///     #[error("The database is not available.")]
+///     #[http_status(ServiceUnavailable)]
+///     #[actix_error(retriable)]
+///     DatabaseUnavailable
+/// }
+///
+/// #[proof_route(get("/users"), retry_budget = "ClientBudget")]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+/// ```
+///
+/// The `export_schema` option records the route so an API contract file can be kept up to
+/// date, with the `openapi` feature enabled `actix_error_proc::openapi::export_schemas()` writes
+/// the recorded routes to their files, at the given path relative to the crate root, usually
+/// from a test. A file holds an entry per method with the name, method, path, `response_schema`,
+/// error type and the variants of that error, which must set the `describe` flag to list them.
+/// Files are only written when their content changes.
+///
+/// ```ignore
+/// #[proof_route(get("/users"), response_schema = "Vec<User>", export_schema = "schema/list_users.json")]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+///
+/// #[test]
+/// fn export_schemas() {
+///     actix_error_proc::openapi::export_schemas().unwrap();
+/// }
+///
+/// // schema/list_users.json
+/// [
+///   {
+///     "error": "SomeError",
+///     "method": "GET",
+///     "name": "list_users",
+///     "path": "/users",
+///     "response_schema": "Vec<User>",
+///     "variants": ["NotFound", "Internal"]
+///   }
+/// ]
+/// ```
+///
+/// The `route_path_const` flag declares the path of the route as a constant named after the
+/// function in `SCREAMING_SNAKE_CASE`, such as `GET_USER_ROUTE_PATH` for `get_user`, which is
+/// `pub` unless the `const_vis` option sets another visibility, setting `const_vis` alone
+/// declares the constant as well.
+///
+/// ```ignore
+/// #[proof_route(get("/users/{id}"), const_vis = "pub(crate)")]
+/// async fn get_user(id: Path<u32>) -> HttpResult<SomeError> // ...
+///
+/// assert_eq!(GET_USER_ROUTE_PATH, "/users/{id}");
+/// ```
+///
+/// The `openapi_tag` and `openapi_summary` options describe a route, with the `openapi`
+/// feature enabled every method of a described route is recorded as an
+/// `actix_error_proc::openapi::RouteDoc` with its method, path, tag and summary, which
+/// `actix_error_proc::openapi::routes()` iterates over to build an OpenAPI document.
+/// Without the feature the options are still accepted and have no effect.
+///
+/// ```ignore
+/// #[proof_route(get("/users"), openapi_tag = "users", openapi_summary = "List users")]
+/// async fn list_users() -> HttpResult<SomeError> // ...
+///
+/// for route in actix_error_proc::openapi::routes() {
+///     println!("{} {}: {:?}", route.method, route.path, route.summary);
+/// }
+/// ```
+///
+/// Handlers can be generic, their type, lifetime and const parameters and their where
+/// clause are kept. actix_web registers each route as a unit struct, which can't carry
+/// the parameters, so a generic handler becomes a function returning the route as an
+/// `actix_web::Resource` for the parameters it's called with, which must be `'static`.
+/// Generic handlers can't set `response_schema`, since it's attached to the route type.
+///
+/// ```ignore
+/// #[proof_route(post("/items"))]
+/// async fn create_item<T>(item: Json<T>) -> HttpResult<SomeError>
+/// where
+///     T: DeserializeOwned + Display,
+/// {
+///     // ...
+/// }
+///
+/// App::new().service(create_item::<User>());
+/// ```
+///
+/// The attributes of the function, such as its documentation, are set on the handler
+/// registered in the app, lint levels such as `#[allow(...)]` also apply to its body.
+#[proc_macro_attribute]
+pub fn proof_route(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let options = parse_macro_input!(attr with Punctuated::<Expr, Token![,]>::parse_terminated);
+    let item = parse_macro_input!(item as ItemFn);
+
+    expand_proof_route(options, item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates the wrapped route, errors point to the tokens that caused them.
+fn expand_proof_route(options: Punctuated<Expr, Token![,]>, mut item: ItemFn) -> syn::Result<TokenStream2> {
+    // the handler is awaited, a sync function fails deep in the generated code.
+    if item.sig.asyncness.is_none() {
+        return Err(syn::Error::new_spanned(
+            item.sig.fn_token,
+            "The `proof_route` attribute requires an async function, add the `async` keyword.",
+        ));
+    }
+
+    let mut options = options.into_iter();
+
+    let first_call = match options.next() {
+        Some(Expr::Call(call)) => call,
+        Some(option) => {
+            return Err(syn::Error::new_spanned(
+                option,
+                "Expected a method call such as `get(\"/\")`.",
+            ));
+        }
+        None => {
+            return Err(syn::Error::new(
+                Span::call_site().into(),
+                "Expected a method call such as `get(\"/\")`.",
+            ));
+        }
+    };
+
+    let mut method_calls = vec![first_call];
+
+    let mut response_schema = None;
+    let mut content_negotiation = false;
+    let mut pre_hooks = Vec::new();
+    let mut export_schema = None;
+    let mut timeout_ms = None;
+    let mut timeout_error = None;
+    let mut compress_threshold = None;
+    let mut retry_budget = None;
+    let mut async_transformer = false;
+    let mut route_path_const = false;
+    let mut const_vis = None;
+    let mut inject_request = None;
+    let mut catch_panic = false;
+    let mut log_request = false;
+    let mut openapi_tag = None;
+    let mut openapi_summary = None;
+
+    for option in options {
+        // errors about the value point to it, or to the flag when there's none.
+        let (name_origin, origin) = match &option {
+            Expr::Assign(option) => (option.left.to_token_stream(), option.right.to_token_stream()),
+            option => (option.to_token_stream(), option.to_token_stream()),
+        };
+
+        let (name, value) = match option {
+            Expr::Call(call) => {
+                method_calls.push(call);
+                continue;
+            }
+            Expr::Assign(option) => (option.left.to_token_stream().to_string(), Some(*option.right)),
+            Expr::Path(flag) => (flag.to_token_stream().to_string(), None),
+            option => {
+                return Err(syn::Error::new_spanned(
+                    option,
+                    "Expected a `name = value` option or a flag.",
+                ));
+            }
+        };
+
+        match name.as_str() {
+            "response_schema" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(schema),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected a string literal for `response_schema`.",
+                    ));
+                };
+
+                response_schema = Some(
+                    parse_literal::<Type>(&schema, "Expected `response_schema` to be a type.")?,
+                );
+            }
+            "content_negotiation" => content_negotiation = flag_value(value)?,
+            "async_transformer" => async_transformer = flag_value(value)?,
+            "catch_panic" => catch_panic = flag_value(value)?,
+            "route_path_const" => route_path_const = flag_value(value)?,
+            "log_request" => {
+                if !cfg!(feature = "tracing") {
+                    return Err(syn::Error::new_spanned(
+                        &name_origin,
+                        "The `log_request` flag requires the `tracing` feature.",
+                    ));
+                }
+
+                log_request = flag_value(value)?;
+            }
+            "timeout_ms" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Int(timeout),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected an integer literal for `timeout_ms`.",
+                    ));
+                };
+
+                timeout
+                    .base10_parse::<u64>()
+                    .map_err(|_| syn::Error::new_spanned(&timeout, "Expected `timeout_ms` to fit in an u64."))?;
+
+                timeout_ms = Some(timeout);
+            }
+            "compress_threshold" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Int(threshold),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected an integer literal for `compress_threshold`.",
+                    ));
+                };
+
+                compress_threshold = Some(
+                    threshold
+                        .base10_parse::<u64>()
+                        .map_err(|_| {
+                            syn::Error::new_spanned(&threshold, "Expected `compress_threshold` to fit in an u64.")
+                        })?,
+                );
+            }
+            "retry_budget" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(budget),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected a string literal for `retry_budget`.",
+                    ));
+                };
+
+                retry_budget = Some(
+                    parse_literal::<Type>(&budget, "Expected `retry_budget` to be a type.")?,
+                );
+            }
+            "timeout_error" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(error),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected a string literal for `timeout_error`.",
+                    ));
+                };
+
+                timeout_error = Some(
+                    parse_literal::<Expr>(&error, "Expected `timeout_error` to be an expression.")?,
+                );
+            }
+            "inject_request" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(name),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected a string literal for `inject_request`.",
+                    ));
+                };
+
+                inject_request = Some(parse_literal::<Ident>(&name, "Expected `inject_request` to be an identifier.")?);
+            }
+            "openapi_tag" | "openapi_summary" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(text),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        format!("Expected a string literal for `{name}`."),
+                    ));
+                };
+
+                if name == "openapi_tag" {
+                    openapi_tag = Some(text);
+                } else {
+                    openapi_summary = Some(text);
+                }
+            }
+            "const_vis" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(vis),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected a string literal for `const_vis`.",
+                    ));
+                };
+
+                const_vis = Some(parse_literal::<Visibility>(&vis, "Expected `const_vis` to be a visibility.")?);
+            }
+            "export_schema" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(file),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected a string literal for `export_schema`.",
+                    ));
+                };
+
+                export_schema = Some(file);
+            }
+            "pre_hook" => {
+                let Some(Expr::Lit(ExprLit {
+                    lit: Lit::Str(hook),
+                    ..
+                })) = value
+                else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected a string literal for `pre_hook`.",
+                    ));
+                };
+
+                pre_hooks.push(parse_literal::<Path>(&hook, "Expected `pre_hook` to be a function path.")?);
+            }
+            "pre_hooks" => {
+                let Some(Expr::Array(hooks)) = value else {
+                    return Err(syn::Error::new_spanned(
+                        &origin,
+                        "Expected an array of string literals for `pre_hooks`.",
+                    ));
+                };
+
+                for hook in hooks.elems {
+                    let Expr::Lit(ExprLit {
+                        lit: Lit::Str(hook),
+                        ..
+                    }) = hook
+                    else {
+                        return Err(syn::Error::new_spanned(
+                            &origin,
+                            "Expected an array of string literals for `pre_hooks`.",
+                        ));
+                    };
+
+                    pre_hooks.push(parse_literal::<Path>(&hook, "Expected `pre_hooks` to be function paths.")?);
+                }
+            }
+            _ => return Err(syn::Error::new_spanned(&name_origin, "Unknown `proof_route` option.")),
+        }
+    }
+
+    let returns_result = match &item.sig.output {
+        ReturnType::Type(_, ty) => route_result(ty),
+        ReturnType::Default => false,
+    };
+
+    let returns_response = match &item.sig.output {
+        ReturnType::Type(_, ty) => route_response(ty),
+        ReturnType::Default => false,
+    };
+
+    if let (true, Some(budget)) = (returns_response, &retry_budget) {
+        return Err(syn::Error::new_spanned(
+            budget,
+            "The `retry_budget` option requires a route that returns errors.",
+        ));
+    }
+
+    if !returns_result && !returns_response {
+        let origin = match &item.sig.output {
+            ReturnType::Type(_, ty) => ty.to_token_stream(),
+            ReturnType::Default => item.sig.ident.to_token_stream(),
+        };
+
+        return Err(syn::Error::new_spanned(
+            origin,
+            "proof_route requires the handler to return HttpResult<E>, Result<HttpResponse, E>, \
+            HttpResponse or impl Responder.",
+        ));
+    }
+
+    // the attributes are moved to the outer handler, which is the one users see,
+    // lint levels are also kept on the inner one since they apply to its body.
+    let mut outer_attrs = Vec::new();
+
+    for attr in std::mem::take(&mut item.attrs) {
+        if ["allow", "warn", "deny", "forbid", "expect"]
+            .iter()
+            .any(|lint| attr.path().is_ident(lint))
+        {
+            item.attrs.push(attr.clone());
+        }
+
+        outer_attrs.push(attr);
+    }
+
+    let original_name = item.sig.ident.clone();
+
+    // the inner function is visible from the body, so its name is suffixed with a hash
+    // of the function to not shadow anything the body calls, such as `__proof_route_name`.
+    let mut hasher = DefaultHasher::new();
+    item.to_token_stream().to_string().hash(&mut hasher);
+
+    let renamed_ident = Ident::new(
+        &format!(
+            "__proof_route_{}_{:016x}",
+            original_name.to_string().trim_start_matches("r#"),
+            hasher.finish()
+        ),
+        original_name.span(),
+    );
+    item.sig.ident = renamed_ident.clone();
+
+    let allowed_methods = ["get", "put", "post", "delete", "patch", "options", "trace", "head"];
+
+    let mut methods = Vec::new();
+    let mut path: Option<LitStr> = None;
+    let mut any_method = false;
+    let method_count = method_calls.len();
+
+    for args in method_calls {
+        let call_methods = if let Expr::Path(method) = &*args.func {
+            let name = method.to_token_stream().to_string();
+
+            // `any` registers the route without a method guard, it's
+            // recorded with the known methods for the schema and openapi.
+            if name == "any" {
+                if method_count > 1 {
+                    return Err(syn::Error::new_spanned(
+                        method,
+                        "The `any` method already includes every method, it can't be listed with others.",
+                    ));
+                }
+
+                any_method = true;
+
+                allowed_methods
+                    .iter()
+                    .map(|allowed| Ident::new(allowed, method.span()))
+                    .collect::<Vec<_>>()
+            } else if name == "route" {
+                route_methods(&args, &allowed_methods)?
+            } else if allowed_methods.contains(&name.as_str()) {
+                vec![Ident::new(&name, method.span())]
+            } else {
+                return Err(syn::Error::new_spanned(
+                    method,
+                    "The method is not a valid HTTP method.",
+                ));
+            }
+        } else {
+            return Err(syn::Error::new_spanned(
+                &args.func,
+                "Expected a method name such as `get`.",
+            ));
+        };
+
+        let method_path = if let Some(arg) = args.args.first() {
+            if let Expr::Lit(ExprLit {
+                lit: Lit::Str(path),
+                ..
+            }) = arg
+            {
+                path.clone()
+            } else {
+                return Err(syn::Error::new_spanned(arg, "Expected a string literal argument."));
+            }
+        } else {
+            return Err(syn::Error::new_spanned(&args, "Expected at least one argument."));
+        };
+
+        // the methods of `route` are its second argument.
+        if matches!(&*args.func, Expr::Path(method) if method.path.is_ident("route")) {
+            if let Some(arg) = args.args.iter().nth(2) {
+                return Err(syn::Error::new_spanned(arg, "Expected only the path and the methods."));
+            }
+        } else if let Some(arg) = args.args.iter().nth(1) {
+            return Err(syn::Error::new_spanned(arg, "Expected only one argument."));
+        }
+
+        match &path {
+            Some(path) if path.value() != method_path.value() => {
+                return Err(syn::Error::new_spanned(
+                    method_path,
+                    format!("All the methods of a route must share the path \"{}\".", path.value()),
+                ));
+            }
+            Some(_) => {}
+            None => path = Some(method_path),
+        }
+
+        for method in call_methods {
+            if methods.contains(&method) {
+                return Err(syn::Error::new_spanned(
+                    &method,
+                    format!("The method `{}` is listed more than once.", method.to_string().to_uppercase()),
+                ));
+            }
+
+            methods.push(method);
+        }
+    }
+
+    // there is always a first method call, so there is always a path.
+    let Some(path) = path else {
+        unreachable!();
+    };
+
+    // the response schema is set on the route type, which a generic handler doesn't have.
+    if let (false, Some(schema)) = (item.sig.generics.params.is_empty(), &response_schema) {
+        return Err(syn::Error::new_spanned(
+            schema,
+            "The `response_schema` option can't be used with a generic handler.",
+        ));
+    }
+
+    let mut extractions = Vec::new();
+    let mut renamed_vars = Vec::new();
+    let mut parameter_names = Vec::<&Ident>::new();
+
+    // every parameter is extracted on its own, a repeated name would hide one of them.
+    for arg in &item.sig.inputs {
+        if let FnArg::Typed(pat_type) = arg {
+            if let Pat::Ident(pat) = &*pat_type.pat {
+                if parameter_names.contains(&&pat.ident) {
+                    return Err(syn::Error::new_spanned(
+                        &pat.ident,
+                        format!("The parameter `{}` is declared more than once.", pat.ident),
+                    ));
+                }
+
+                parameter_names.push(&pat.ident);
+            }
+        }
+    }
+
+    for (idx, arg) in item.sig.inputs.iter_mut().enumerate() {
+        if let FnArg::Typed(pat_type) = arg {
+            let var_pat = &pat_type.pat;
+            let var_name = quote::format_ident!("__arg_{idx}", span = var_pat.span());
+            let ty = pat_type.ty.clone();
+
+            let mut error_variant = None;
+            let mut error_default = None;
+            let mut error_map = None;
+            let mut optional = None;
+            let mut attrs = Vec::new();
+
+            for attr in pat_type.attrs.drain(..) {
+                if attr.path().is_ident("or") {
+                    error_variant = Some(attr.parse_args::<Expr>().map_err(|_| {
+                        syn::Error::new_spanned(&attr, "Expected an error such as `#[or(SomeError::Variant)]`.")
+                    })?);
+                } else if attr.path().is_ident("or_default") {
+                    error_default = Some(attr);
+                } else if attr.path().is_ident("or_map") {
+                    let map = attr.parse_args::<Expr>().map_err(|_| {
+                        syn::Error::new_spanned(&attr, "Expected a function such as `#[or_map(|err| SomeError::from(err))]`.")
+                    })?;
+
+                    error_map = Some((attr, map));
+                } else if attr.path().is_ident("optional") {
+                    optional = Some(attr);
+                } else {
+                    attrs.push(attr);
+                }
+            }
+
+            pat_type.attrs = attrs;
+
+            if let (Some(_), Some((attr, _))) = (&error_variant, &error_map) {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "The `or` and `or_map` attributes are exclusive.",
+                ));
+            }
+
+            // the default error is chosen by the derive, which adds `__or_default` to the enum.
+            if let Some(attr) = error_default {
+                if error_variant.is_some() || error_map.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        attr,
+                        "The `or_default` attribute can't be used with `or` or `or_map`.",
+                    ));
+                }
+
+                let error_type = match &item.sig.output {
+                    ReturnType::Type(_, ty) => result_error_type(ty),
+                    ReturnType::Default => None,
+                }
+                .ok_or_else(|| {
+                    syn::Error::new_spanned(
+                        &attr,
+                        "The `or_default` attribute requires the route to return an `HttpResult` of its error.",
+                    )
+                })?;
+
+                // an enum without a default error has no `__or_default`, the
+                // missing function is reported on the attribute.
+                error_variant = Some(syn::parse_quote_spanned! { attr.meta.span() =>
+                    <#error_type>::__or_default()
+                });
+            }
+
+            // optional parameters are `None` when the extraction fails for any reason.
+            if let Some(optional) = optional {
+                if error_variant.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        optional,
+                        "The `optional` and `or` attributes are exclusive.",
+                    ));
+                }
+
+                if error_map.is_some() {
+                    return Err(syn::Error::new_spanned(
+                        optional,
+                        "The `optional` and `or_map` attributes are exclusive.",
+                    ));
+                }
+
+                *pat_type.ty = parse_quote! { ::core::option::Option<#ty> };
+
+                extractions.push(quote! {
+                    let #var_name = <#ty as actix_web::FromRequest>::from_request(&req, &mut payload)
+                        .await
+                        .ok();
+                });
+
+                renamed_vars.push(var_name.clone());
+                continue;
+            }
+
+            let error_extractor = if let Some(error) = error_variant {
+                quote! { Err(_) => return #error.into() }
+            } else if let Some((_, map)) = error_map {
+                // passing the closure to a function lets its parameter be inferred from the error.
+                quote! {
+                    Err(err) => {
+                        fn __proof_route_map_error<E, R: ::core::convert::Into<actix_web::HttpResponse>>(
+                            err: E,
+                            map: impl ::core::ops::FnOnce(E) -> R,
+                        ) -> actix_web::HttpResponse {
+                            map(err).into()
+                        }
+
+                        return __proof_route_map_error(err, #map);
+                    }
+                }
+            } else if payload_extractor(&ty) {
+                quote! { Err(_) => ::core::unreachable!("The `Payload` extractor doesn't fail.") }
+            } else {
+                quote! { Err(err) => return err.into() }
+            };
+
+            extractions.push(quote! {
+                let #var_name: #ty = match <#ty as actix_web::FromRequest>::from_request(&req, &mut payload).await {
+                    Ok(v) => v,
+                    #error_extractor,
+                };
+            });
+
+            renamed_vars.push(var_name.clone());
+        }
+    }
+
+    let (schema_doc, schema_impl) = match &response_schema {
+        Some(schema) => {
+            let doc = format!("Returns: `{}` as JSON", schema.to_token_stream());
+
+            (
+                quote! { #[doc = #doc] },
+                quote! {
+                    impl #original_name {
+                        /// The `TypeId` of the type this route responds with on success.
+                        pub const RESPONSE_SCHEMA: ::core::any::TypeId =
+                            ::core::any::TypeId::of::<#schema>();
+                    }
+                },
+            )
+        }
+        None => (quote! {}, quote! {}),
+    };
+
+    // the injected request is a hidden first parameter of the inner function.
+    let injected_request = inject_request.map(|name| {
+        item.sig.inputs.insert(0, parse_quote! { #name: actix_web::HttpRequest });
+        quote! { ::core::clone::Clone::clone(&req), }
+    });
+
+    let handler_call = quote! { #renamed_ident(#injected_request #(#renamed_vars),*) };
+
+    // without a `timeout_error` the route responds with a `504 Gateway Timeout`.
+    let handler_call = match (timeout_ms, timeout_error) {
+        (Some(timeout), error) => {
+            let error = match error {
+                Some(error) => quote! { ::core::convert::Into::<actix_web::HttpResponse>::into(#error) },
+                None => quote! { actix_web::HttpResponse::GatewayTimeout().body("request timed out") },
+            };
+
+            quote! {
+                match actix_web::rt::time::timeout(
+                    ::core::time::Duration::from_millis(#timeout),
+                    #handler_call
+                ).await {
+                    ::core::result::Result::Ok(result) => result,
+                    ::core::result::Result::Err(_) => {
+                        return #error;
+                    }
+                }
+            }
+        }
+        (None, None) => quote! { #handler_call.await },
+        (None, Some(error)) => {
+            return Err(syn::Error::new_spanned(
+                error,
+                "The `timeout_error` option requires a `timeout_ms`.",
+            ));
+        }
+    };
+
+    let negotiation = if content_negotiation {
+        quote! {
+            match actix_error_proc::ResponseFormat::negotiate(&req) {
+                ::core::option::Option::Some(format) => {
+                    actix_web::HttpMessage::extensions_mut(&req).insert(format);
+                }
+                ::core::option::Option::None => {
+                    return actix_web::HttpResponse::NotAcceptable().finish();
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let retry_check = retry_budget.map(|budget| {
+        // an error without retriable variants has no `is_retriable`, which is reported on the budget.
+        let is_retriable = quote_spanned! { budget.span() => r.is_retriable() };
+
+        quote! {
+            if #is_retriable {
+                let budget = req.app_data::<actix_web::web::Data<#budget>>();
+                let client = req.peer_addr().map(|addr| addr.ip());
+
+                if let (::core::option::Option::Some(budget), ::core::option::Option::Some(client)) = (budget, client) {
+                    if !actix_error_proc::RetryBudget::consume(&***budget, client) {
+                        return actix_web::HttpResponse::TooManyRequests().finish();
+                    }
+                }
+            }
+        }
+    });
+
+    let infallible = match &item.sig.output {
+        ReturnType::Type(_, ty) => infallible_result(ty),
+        ReturnType::Default => false,
+    };
+
+    // `Infallible` doesn't implement `Into<HttpResponse>`, but it can be matched.
+    let into_response = if infallible {
+        quote! { match r {} }
+    } else if async_transformer {
+        quote! { r.async_into().await }
+    } else {
+        quote! { r.into() }
+    };
+
+    // routes that can't fail return the response as it is.
+    let handler_result = if returns_response {
+        quote! {
+            actix_web::Responder::respond_to(#handler_call, &req).map_into_boxed_body()
+        }
+    } else {
+        quote! {
+            match #handler_call {
+                ::core::result::Result::Ok(r) => r,
+                ::core::result::Result::Err(r) => {
+                    #retry_check
+                    #into_response
+                }
+            }
+        }
+    };
+
+    let route_body = quote! {
+        #(
+            if let ::core::result::Result::Err(err) = #pre_hooks(&req) {
+                return ::core::convert::Into::<actix_web::HttpResponse>::into(err);
+            }
+        )*
+
+        #negotiation
+
+        #(#extractions)*
+
+        #handler_result
+    };
+
+    // the route is polled inside `catch_unwind`, so a panic while
+    // polling it responds with an error instead of ending the worker.
+    // the payload of the panic is logged with the `tracing` feature.
+    let (panic_payload, panic_log) = if cfg!(feature = "tracing") {
+        let log = quote! {
+            let message = ::core::option::Option::or_else(
+                ::core::option::Option::map(payload.downcast_ref::<&str>(), ::std::string::ToString::to_string),
+                || payload.downcast_ref::<::std::string::String>().cloned(),
+            );
+
+            tracing::error!(panic = ?message, "HTTP handler panicked");
+        };
+
+        (quote! { payload }, log)
+    } else {
+        (quote! { _ }, quote! {})
+    };
+
+    let route_body = if catch_panic {
+        quote! {
+            let mut route = ::core::pin::pin!(async { #route_body });
+
+            let result: ::std::thread::Result<actix_web::HttpResponse> = ::std::future::poll_fn(|cx| {
+                match ::std::panic::catch_unwind(::std::panic::AssertUnwindSafe(|| {
+                    ::core::future::Future::poll(route.as_mut(), cx)
+                })) {
+                    ::core::result::Result::Ok(::core::task::Poll::Ready(response)) => {
+                        ::core::task::Poll::Ready(::core::result::Result::Ok(response))
+                    }
+                    ::core::result::Result::Ok(::core::task::Poll::Pending) => ::core::task::Poll::Pending,
+                    ::core::result::Result::Err(payload) => {
+                        ::core::task::Poll::Ready(::core::result::Result::Err(payload))
+                    }
+                }
+            }).await;
+
+            match result {
+                ::core::result::Result::Ok(response) => response,
+                ::core::result::Result::Err(#panic_payload) => {
+                    #panic_log
+                    actix_web::HttpResponse::InternalServerError().body("internal error")
+                }
+            }
+        }
+    } else {
+        route_body
+    };
+
+    // the `Compress` middleware doesn't encode responses that already set an encoding.
+    let route_body = match compress_threshold {
+        // the early returns of the body return from the async block, so
+        // every response goes through the threshold check.
+        Some(threshold) => quote! {
+            let mut response: actix_web::HttpResponse = async { #route_body }.await;
+
+            if let actix_web::body::BodySize::Sized(size) =
+                actix_web::body::MessageBody::size(response.body())
+            {
+                if size <= #threshold {
+                    response.headers_mut().insert(
+                        actix_web::http::header::CONTENT_ENCODING,
+                        actix_web::http::header::HeaderValue::from_static("identity"),
+                    );
+                }
+            }
+
+            response
+        },
+        None => route_body,
+    };
+
+    // the request is logged around everything else, so the status is the one sent.
+    let route_body = if log_request {
+        quote! {
+            let started = ::std::time::Instant::now();
+
+            tracing::info!(
+                method = %req.method(),
+                path = %req.path(),
+                remote = ?req.peer_addr(),
+                "HTTP request received"
+            );
+
+            let response: actix_web::HttpResponse = async { #route_body }.await;
+
+            tracing::info!(
+                status = response.status().as_u16(),
+                elapsed = ?started.elapsed(),
+                "HTTP response sent"
+            );
+
+            response
+        }
+    } else {
+        route_body
+    };
+
+    // a single handler can't have more than one method attribute,
+    // so many methods are registered with `actix_web::route`.
+    let route_attr = match methods.as_slice() {
+        [method] => quote! { #[actix_web::#method(#path)] },
+        methods => {
+            let methods = methods
+                .iter()
+                .map(|method| method.to_string().to_uppercase());
+
+            quote! { #[actix_web::route(#path, #(method = #methods),*)] }
+        }
+    };
+
+    let cfg_attrs = outer_attrs.iter().filter(|attr| attr.path().is_ident("cfg")).collect::<Vec<_>>();
+
+    // setting a visibility for the constant asks for it as well.
+    let route_path = (route_path_const || const_vis.is_some()).then(|| {
+        let const_vis = const_vis.unwrap_or_else(|| parse_quote! { pub });
+        let const_name = Ident::new(
+            &format!("{}_ROUTE_PATH", snake_case(original_name.to_string().trim_start_matches("r#")).to_uppercase()),
+            original_name.span(),
+        );
+        let const_doc = format!("The path of the `{original_name}` route.");
+
+        quote! {
+            #(#cfg_attrs)*
+            #[doc = #const_doc]
+            #const_vis const #const_name: &str = #path;
+        }
+    });
+
+    // the routes are recorded by the runtime crate, which ignores them without the `openapi` feature.
+    let openapi_option = |text: &Option<LitStr>| match text {
+        Some(text) => quote! { ::core::option::Option::Some(#text) },
+        None => quote! { ::core::option::Option::None },
+    };
+
+    let openapi_routes = if openapi_tag.is_some() || openapi_summary.is_some() {
+        let tag = openapi_option(&openapi_tag);
+        let summary = openapi_option(&openapi_summary);
+
+        methods
+            .iter()
+            .map(|method| {
+                let method = method.to_string().to_uppercase();
+
+                quote! {
+                    #(#cfg_attrs)*
+                    actix_error_proc::__openapi_route! {
+                        method: #method,
+                        path: #path,
+                        tag: #tag,
+                        summary: #summary,
+                    }
+                }
+            })
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    // the schema is recorded as data, `openapi::export_schemas` writes it at runtime.
+    let schema_route = export_schema.map(|file| {
+        let error = match &item.sig.output {
+            ReturnType::Type(_, ty) => result_error_type(ty),
+            ReturnType::Default => None,
+        };
+
+        let name = original_name.to_string().trim_start_matches("r#").to_owned();
+        let methods = methods.iter().map(|method| method.to_string().to_uppercase());
+        let response_schema = openapi_option(&response_schema.as_ref().map(|schema| {
+            LitStr::new(&type_string(schema), schema.span())
+        }));
+        let error_name = openapi_option(&error.map(|error| LitStr::new(&type_string(error), error.span())));
+
+        // the variants are listed by the `describe` flag of the error.
+        let variants = match error.filter(|error| listed_error(error, &item.sig.generics)) {
+            Some(error) => quote_spanned! { error.span() =>
+                ::core::option::Option::Some(<#error>::VARIANT_NAMES)
+            },
+            None => quote! { ::core::option::Option::None },
+        };
+
+        quote! {
+            #(#cfg_attrs)*
+            actix_error_proc::__schema_route! {
+                file: ::core::concat!(::core::env!("CARGO_MANIFEST_DIR"), "/", #file),
+                name: #name,
+                methods: &[#(#methods),*],
+                path: #path,
+                response_schema: #response_schema,
+                error: #error_name,
+                variants: #variants,
+            }
+        }
+    });
+
+    let generics = &item.sig.generics;
+
+    // actix_web registers a handler as a unit struct, which can't carry the parameters of
+    // a generic one, so it's served by a function returning the resource of a single
+    // instantiation, such as `create_item::<User>()`.
+    let handler = if generics.params.is_empty() && any_method {
+        let vis = &item.vis;
+        let handler_name = Ident::new(
+            &format!("__proof_route_handler_{}", original_name.to_string().trim_start_matches("r#")),
+            original_name.span(),
+        );
+        let resource_name = original_name.to_string();
+
+        // the route attributes of actix_web require a method, so the
+        // route type registers a resource with an unguarded route itself.
+        quote! {
+            #[doc(hidden)]
+            async fn #handler_name(req: actix_web::HttpRequest, payload: actix_web::web::Payload) -> impl actix_web::Responder {
+                #[doc(hidden)]
+                #item
+
+                #[allow(unused)]
+                #[doc(hidden)]
+                let mut payload = payload.into_inner();
+
+                #route_body
+            }
+
+            #(#outer_attrs)*
+            #schema_doc
+            #[allow(non_camel_case_types)]
+            #vis struct #original_name;
+
+            impl actix_web::dev::HttpServiceFactory for #original_name {
+                fn register(self, config: &mut actix_web::dev::AppService) {
+                    let resource = actix_web::web::resource(#path)
+                        .name(#resource_name)
+                        .route(actix_web::web::route().to(#handler_name));
+
+                    actix_web::dev::HttpServiceFactory::register(resource, config);
+                }
+            }
+        }
+    } else if generics.params.is_empty() {
+        quote! {
+            #(#outer_attrs)*
+            #schema_doc
+            #route_attr
+            async fn #original_name(req: actix_web::HttpRequest, payload: actix_web::web::Payload) -> impl actix_web::Responder {
+                #[doc(hidden)]
+                #item
+
+                #[allow(unused)]
+                #[doc(hidden)]
+                let mut payload = payload.into_inner();
+
+                #route_body
+            }
+        }
+    } else {
+        let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+        let type_generics = type_generics.as_turbofish();
+        let vis = &item.vis;
+        let handler_name = Ident::new(
+            &format!("__proof_route_handler_{}", original_name.to_string().trim_start_matches("r#")),
+            original_name.span(),
+        );
+
+        // actix_web requires handlers to be `'static`, so their type parameters are too, the
+        // bounds of the parameters move to the where clause so they aren't split in two places.
+        let mut static_generics = generics.clone();
+
+        let static_bounds = static_generics
+            .type_params_mut()
+            .map(|param| {
+                let ident = &param.ident;
+                let bounds = std::mem::take(&mut param.bounds);
+                let bounds = bounds.iter();
+                param.colon_token = None;
+
+                quote! { #ident: #(#bounds +)* 'static, }
+            })
+            .collect::<Vec<_>>();
+
+        let (static_generics, _, _) = static_generics.split_for_impl();
+
+        let where_predicates = where_clause.map(|clause| &clause.predicates);
+
+        let routes = methods.iter().map(|method| {
+            let method = Ident::new(&method.to_string().to_uppercase(), method.span());
+
+            quote! {
+                .route(actix_web::web::method(actix_web::http::Method::#method).to(#handler_name #type_generics))
+            }
+        });
+
+        let routes = if any_method {
+            vec![quote! { .route(actix_web::web::route().to(#handler_name #type_generics)) }]
+        } else {
+            routes.collect()
+        };
+
+        // the name follows the route, such as `UserController_create` in `proof_routes`.
+        quote! {
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            async fn #handler_name #impl_generics(
+                req: actix_web::HttpRequest,
+                payload: actix_web::web::Payload,
+            ) -> impl actix_web::Responder #where_clause {
+                #[doc(hidden)]
+                #item
+
+                #[allow(unused)]
+                #[doc(hidden)]
+                let mut payload = payload.into_inner();
+
+                #route_body
+            }
+
+            #(#outer_attrs)*
+            #vis fn #original_name #static_generics() -> actix_web::Resource
+            where
+                #(#static_bounds)*
+                #where_predicates
+            {
+                actix_web::web::resource(#path) #(#routes)*
+            }
+        }
+    };
+
+    Ok(quote! {
+        #(#openapi_routes)*
+
+        #route_path
+
+        #handler
+
+        #schema_impl
+
+        #schema_route
+    })
+}
+
+/// Registers the associated functions of an `impl` block marked with `#[proof_route(...)]`
+/// as routes, each of them is served by a function named `{TypeName}_{fn_name}` placed
+/// after the block, while the body stays in the block so it can use `Self` and its items.
+///
+/// ```ignore
+/// struct UserController;
+///
+/// #[proof_routes]
+/// impl UserController {
+///     const PAGE_SIZE: usize = 20;
+///
+///     #[proof_route(get("/users"))]
+///     async fn list() -> HttpResult<SomeError> {
+///         let size = Self::PAGE_SIZE;
+///         // ...
+///     }
+/// }
+///
+/// App::new().service(UserController_list);
+/// ```
+///
+/// The functions can't take `self`, and the block can't be generic or implement a trait.
+/// Generic functions are served as generic handlers of `proof_route`, so the route is a
+/// function returning the resource for the parameters it's called with.
+///
+/// ```ignore
+/// #[proof_routes]
+/// impl ItemController {
+///     #[proof_route(post("/items"))]
+///     async fn create<T: DeserializeOwned>(item: Json<T>) -> HttpResult<SomeError> {
+///         // ...
+///     }
+/// }
+///
+/// App::new().service(ItemController_create::<Item>());
+/// ```
+#[proc_macro_attribute]
+pub fn proof_routes(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(Span::call_site().into(), "The `proof_routes` attribute doesn't take options.")
+            .into_compile_error()
+            .into();
+    }
+
+    let item = parse_macro_input!(item as ItemImpl);
+
+    expand_proof_routes(item)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates a route for every associated function marked with `proof_route`,
+/// the functions are left in the block without the extraction attributes.
+fn expand_proof_routes(mut item: ItemImpl) -> syn::Result<TokenStream2> {
+    if let Some((_, path, _)) = &item.trait_ {
+        return Err(syn::Error::new_spanned(path, "Routes can't be declared in trait implementations."));
+    }
+
+    if !item.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(&item.generics, "Routes can't be declared in generic `impl` blocks."));
+    }
+
+    let self_ty = item.self_ty.clone();
+
+    let type_name = match &*self_ty {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last().map(|segment| segment.ident.clone()),
+        _ => None,
+    }
+    .ok_or_else(|| syn::Error::new_spanned(&self_ty, "Expected a type name such as `UserController`."))?;
+
+    let mut routes = Vec::new();
+
+    for impl_item in &mut item.items {
+        let ImplItem::Fn(method) = impl_item else {
+            continue;
+        };
+
+        let Some(position) = method.attrs.iter().position(|attr| attr.path().is_ident("proof_route")) else {
+            continue;
+        };
+
+        let route_attr = method.attrs.remove(position);
+
+        let options = route_attr
+            .parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+            .map_err(|_| syn::Error::new_spanned(&route_attr, "Expected a method call such as `get(\"/\")`."))?;
+
+        if let Some(receiver) = method.sig.receiver() {
+            return Err(syn::Error::new_spanned(
+                receiver,
+                "Routes in `impl` blocks must be associated functions, `self` is not available.",
+            ));
+        }
+
+        if method.sig.asyncness.is_none() {
+            return Err(syn::Error::new_spanned(
+                method.sig.fn_token,
+                "The `proof_route` attribute requires an async function, add the `async` keyword.",
+            ));
+        }
+
+        let fn_name = method.sig.ident.clone();
+        let mut inputs = Punctuated::<FnArg, Token![,]>::new();
+        let mut args = Vec::new();
+
+        for (idx, input) in method.sig.inputs.iter_mut().enumerate() {
+            let FnArg::Typed(pat_type) = input else {
+                continue;
+            };
+
+            let arg = match &*pat_type.pat {
+                Pat::Ident(pat) => pat.ident.clone(),
+                pat => quote::format_ident!("__arg_{idx}", span = pat.span()),
+            };
+
+            // the extraction attributes belong to the route, the function gets the extracted value.
+            let (route_attrs, attrs) = pat_type
+                .attrs
+                .drain(..)
+                .partition::<Vec<_>, _>(|attr| {
+                    ["or", "or_default", "or_map", "optional"]
+                        .iter()
+                        .any(|name| attr.path().is_ident(name))
+                });
+
+            let ty = pat_type.ty.clone();
+
+            if route_attrs.iter().any(|attr| attr.path().is_ident("optional")) {
+                *pat_type.ty = parse_quote! { ::core::option::Option<#ty> };
+            }
+
+            pat_type.attrs = attrs;
+            inputs.push(parse_quote! { #(#route_attrs)* #arg: #ty });
+            args.push(arg);
+        }
+
+        // the injected request is declared by the route, so it's passed on to the function.
+        let injected_request = options.iter().find_map(|option| match option {
+            Expr::Assign(assign) if matches!(&*assign.left, Expr::Path(path) if path.path.is_ident("inject_request")) => {
+                match &*assign.right {
+                    Expr::Lit(ExprLit { lit: Lit::Str(name), .. }) => name.parse::<Ident>().ok(),
+                    _ => None,
+                }
+            }
+            _ => None,
+        });
+
+        if let Some(name) = injected_request {
+            method.sig.inputs.insert(0, parse_quote! { #name: actix_web::HttpRequest });
+            args.insert(0, name);
+        }
+
+        let mut sig = method.sig.clone();
+        sig.ident = quote::format_ident!("{type_name}_{fn_name}", span = fn_name.span());
+        sig.inputs = inputs;
+
+        let attrs = method
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("cfg"));
+
+        let vis = &method.vis;
+
+        // the wrapper keeps the parameters of a generic function, so `proof_route` serves it
+        // as a generic handler, and names them in the call since they can't be inferred.
+        let params = sig.generics.params.iter().filter_map(|param| match param {
+            GenericParam::Type(param) => Some(&param.ident),
+            GenericParam::Const(param) => Some(&param.ident),
+            GenericParam::Lifetime(_) => None,
+        });
+
+        let wrapper: ItemFn = parse_quote! {
+            #[allow(non_snake_case)]
+            #(#attrs)*
+            #vis #sig {
+                <#self_ty>::#fn_name::<#(#params),*>(#(#args),*).await
+            }
+        };
+
+        routes.push(expand_proof_route(options, wrapper)?);
+    }
+
+    Ok(quote! {
+        #item
+
+        #(#routes)*
+    })
+}
+
+/// Declares an enum with the variants of many `ActixError` enums, such as the ones
+/// of each module of an application, and a `From` implementation for each of them,
+/// which derives `ActixError` so the variants keep their status.
+///
+/// ```ignore
+/// unify_errors!(
+///     #[derive(Error, Debug)]
+///     pub AppError { auth::AuthError, DatabaseError }
+/// );
+///
+/// // expands to an enum such as this one, with `From<AuthError>` and `From<DatabaseError>`.
+/// #[derive(Error, Debug, ActixError)]
+/// pub enum AppError {
+///     #[error("unauthorized")]
+///     #[http_status(Unauthorized)]
+///     Unauthorized, // from `AuthError`.
+///
+///     #[error("user not found")]
+///     #[http_status(NotFound)]
+///     NotFound // from `DatabaseError`.
+/// }
+/// ```
+///
+/// The variants are copied with their attributes, the ones without a status get the
+/// `default_status` of their enum, while the other options of the enums aren't copied
+/// and are set with `#[actix_error(...)]` before the visibility. The thiserror attributes
+/// are left out unless the attributes derive `Error`.
+///
+/// The errors must set the `describe` flag, which declares a hidden macro with their
+/// definition next to them, so they must be non generic enums declared in the same crate,
+/// named by their path such as `auth::AuthError` rather than an imported name, and the types
+/// of their fields must be in scope where the enum is unified. Two variants with the same
+/// name are a compile error.
+#[proc_macro]
+pub fn unify_errors(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as UnifyErrors);
+
+    let Some((first, pending)) = input.errors.split_first() else {
+        return syn::Error::new_spanned(&input.ident, "Expected at least one error to unify.")
+            .into_compile_error()
+            .into();
+    };
+
+    unify_step(&input.header(), first, pending, &[]).into()
+}
+
+/// Copies the definition of an error given by its macro and asks for the next one,
+/// the enum is generated once every definition is known.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __unify_errors(input: TokenStream) -> TokenStream {
+    let UnifyStep { header, pending, mut unified, current } = parse_macro_input!(input as UnifyStep);
+
+    unified.push(current);
+
+    match pending.split_first() {
+        Some((next, pending)) => unify_step(&header, next, pending, &unified),
+        None => syn::parse2::<UnifyErrors>(header)
+            .and_then(|input| expand_unify_errors(input, &unified))
+            .unwrap_or_else(syn::Error::into_compile_error),
+    }
+    .into()
+}
+
+/// The input of `unify_errors!`, such as `pub AppError { AuthError, DatabaseError }`.
+struct UnifyErrors {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    errors: Vec<Path>,
+}
+
+impl UnifyErrors {
+    /// The attributes, visibility and name, passed along the steps.
+    fn header(&self) -> TokenStream2 {
+        let UnifyErrors { attrs, vis, ident, .. } = self;
+
+        quote! { #(#attrs)* #vis #ident {} }
+    }
+}
+
+impl syn::parse::Parse for UnifyErrors {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+
+        let content;
+        syn::braced!(content in input);
+
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            errors: Punctuated::<Path, Token![,]>::parse_terminated_with(&content, Path::parse_mod_style)?
+                .into_iter()
+                .collect(),
+        })
+    }
+}
+
+/// The input of `__unify_errors!`, such as `[header] [pending] [unified] Path { definition }`.
+struct UnifyStep {
+    header: TokenStream2,
+    pending: Vec<Path>,
+    unified: Vec<(Path, DeriveInput)>,
+    current: (Path, DeriveInput),
+}
+
+impl syn::parse::Parse for UnifyStep {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let header;
+        syn::bracketed!(header in input);
+
+        let pending;
+        syn::bracketed!(pending in input);
+
+        let unified_content;
+        syn::bracketed!(unified_content in input);
+
+        let mut unified = Vec::new();
+
+        while !unified_content.is_empty() {
+            unified.push(parse_definition(&unified_content)?);
+        }
+
+        Ok(Self {
+            header: header.parse()?,
+            pending: Punctuated::<Path, Token![,]>::parse_terminated_with(&pending, Path::parse_mod_style)?
+                .into_iter()
+                .collect(),
+            unified,
+            current: parse_definition(input)?,
+        })
+    }
+}
+
+/// Parses the path of an error followed by its braced definition.
+fn parse_definition(input: syn::parse::ParseStream) -> syn::Result<(Path, DeriveInput)> {
+    let path = Path::parse_mod_style(input)?;
+
+    let definition;
+    syn::braced!(definition in input);
+
+    Ok((path, definition.parse()?))
+}
+
+/// Calls the macro of the next error with the state of the unification.
+fn unify_step(header: &TokenStream2, next: &Path, pending: &[Path], unified: &[(Path, DeriveInput)]) -> TokenStream2 {
+    let unified = unified.iter().map(|(path, definition)| quote! { #path { #definition } });
+
+    // the macro is declared next to the error, named after it.
+    let mut describe = next.clone();
+
+    if let Some(segment) = describe.segments.last_mut() {
+        segment.ident = describe_name(&segment.ident);
+        segment.arguments = PathArguments::None;
+    }
+
+    quote! {
+        #describe! {
+            actix_error_proc::__unify_errors {
+                [#header] [#(#pending),*] [#(#unified)*] #next
+            }
+        }
+    }
+}
+
+/// Generates the unified enum and the `From` implementations for each error.
+fn expand_unify_errors(input: UnifyErrors, unified: &[(Path, DeriveInput)]) -> syn::Result<TokenStream2> {
+    let UnifyErrors { attrs, vis, ident, .. } = input;
+
+    // without thiserror its attributes would be unknown.
+    let derives_error = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .filter_map(|attr| attr.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated).ok())
+        .any(|paths| paths.iter().any(|path| path.segments.last().is_some_and(|segment| segment.ident == "Error")));
+
+    let mut variants = Vec::<(&Ident, &Path)>::new();
+    let mut enum_variants = Vec::new();
+    let mut from_impls = Vec::new();
+
+    for (path, definition) in unified {
+        let Data::Enum(data_enum) = &definition.data else {
+            return Err(syn::Error::new_spanned(path, "Only enums can be unified."));
+        };
+
+        if !definition.generics.params.is_empty() {
+            return Err(syn::Error::new_spanned(path, "Generic enums can't be unified."));
+        }
+
+        let mut arms = Vec::new();
+
+        for variant in &data_enum.variants {
+            let name = &variant.ident;
+
+            if let Some((_, other)) = variants.iter().find(|(other, _)| *other == name) {
+                return Err(syn::Error::new_spanned(
+                    path,
+                    format!(
+                        "The `{name}` variant is already taken by `{}`, rename one of them.",
+                        other.to_token_stream().to_string().replace(' ', "")
+                    ),
+                ));
+            }
+
+            variants.push((name, path));
+
+            let mut variant = variant.clone();
+
+            if !derives_error {
+                variant.attrs.retain(|attr| !attr.path().is_ident("error"));
+
+                for field in &mut variant.fields {
+                    field.attrs.retain(|attr| {
+                        !["from", "source", "backtrace"].iter().any(|name| attr.path().is_ident(name))
+                    });
+                }
+            }
+
+            let bindings = variant
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| match &field.ident {
+                    Some(field) => field.clone(),
+                    None => quote::format_ident!("__{index}"),
+                })
+                .collect::<Vec<_>>();
+
+            let pattern = match &variant.fields {
+                Fields::Named(_) => quote! { { #(#bindings),* } },
+                Fields::Unnamed(_) => quote! { (#(#bindings),*) },
+                Fields::Unit => quote! {},
+            };
+
+            // the arms of variants behind a `cfg` are behind it too.
+            let cfgs = variant.attrs.iter().filter(|attr| attr.path().is_ident("cfg"));
+
+            arms.push(quote! { #(#cfgs)* #path::#name #pattern => Self::#name #pattern });
+            enum_variants.push(variant);
+        }
+
+        from_impls.push(quote! {
+            impl ::core::convert::From<#path> for #ident {
+                fn from(error: #path) -> Self {
+                    match error {
+                        #(#arms),*
+                    }
+                }
+            }
+        });
+    }
+
+    // the derive goes first, so its helpers can be used in the attributes.
+    Ok(quote! {
+        #[derive(actix_error_proc::ActixError)]
+        #(#attrs)*
+        #vis enum #ident {
+            #(#enum_variants),*
+        }
+
+        #(#from_impls)*
+    })
+}
+
+/// Declares an enum holding one of many errors that convert into a response, with a
+/// variant per error named as its type without the `Error` suffix, which converts
+/// into the response of the error it holds.
+///
+/// ```ignore
+/// error_chain!(pub RouteError: AuthError, DatabaseError);
+///
+/// // expands to an enum such as this one, with `From<AuthError>` and `From<DatabaseError>`.
+/// pub enum RouteError {
+///     Auth(AuthError),
+///     Database(DatabaseError)
+/// }
+/// ```
+///
+/// The errors only need to implement `Into<HttpResponse>`, and the enum only implements
+/// that conversion and `From`, so it isn't an error, it has no message and it can't be
+/// converted into an `actix_web::Error`. Attributes such as `#[derive(Debug)]` can be
+/// set before the visibility. `unify_errors!` gives those and the `actix_error` options
+/// for `ActixError` enums.
+#[proc_macro]
+pub fn error_chain(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ErrorChain);
+
+    expand_error_chain(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The input of `error_chain!`, such as `pub RouteError: AuthError, DatabaseError`.
+struct ErrorChain {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    ident: Ident,
+    errors: Punctuated<Path, Token![,]>,
+}
+
+impl syn::parse::Parse for ErrorChain {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+        input.parse::<Token![:]>()?;
+
+        Ok(Self {
+            attrs,
+            vis,
+            ident,
+            errors: Punctuated::parse_terminated_with(input, Path::parse_mod_style)?,
+        })
+    }
+}
+
+/// Generates the chained enum, its `From` implementations and the response conversion.
+fn expand_error_chain(input: ErrorChain) -> syn::Result<TokenStream2> {
+    let ErrorChain { attrs, vis, ident, errors } = input;
+
+    if errors.is_empty() {
+        return Err(syn::Error::new_spanned(&ident, "Expected at least one error to chain."));
+    }
+
+    let mut variants = Vec::<(Ident, &Path)>::new();
+
+    for error in &errors {
+        let Some(segment) = error.segments.last() else {
+            continue;
+        };
+
+        // `AuthError` becomes `Auth`, a type named `Error` keeps its name.
+        let type_name = segment.ident.to_string();
+        let name = match type_name.strip_suffix("Error") {
+            Some(name) if !name.is_empty() => Ident::new(name, segment.ident.span()),
+            _ => segment.ident.clone(),
+        };
+
+        if let Some((_, other)) = variants.iter().find(|(other, _)| *other == name) {
+            return Err(syn::Error::new_spanned(
+                error,
+                format!(
+                    "The `{name}` variant is already taken by `{}`, import one of them with another name.",
+                    other.to_token_stream().to_string().replace(' ', "")
+                ),
+            ));
+        }
+
+        variants.push((name, error));
+    }
+
+    let names = variants.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    let paths = variants.iter().map(|(_, path)| path).collect::<Vec<_>>();
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis enum #ident {
+            #(#names(#paths)),*
+        }
+
+        #(
+            impl ::core::convert::From<#paths> for #ident {
+                fn from(error: #paths) -> Self {
+                    Self::#names(error)
+                }
+            }
+        )*
+
+        impl ::core::convert::Into<actix_web::HttpResponse> for #ident {
+            fn into(self) -> actix_web::HttpResponse {
+                match self {
+                    #(Self::#names(error) => ::core::convert::Into::<actix_web::HttpResponse>::into(error)),*
+                }
+            }
+        }
+    })
+}
+
+/// The warning for errors responding with a `2xx` status, which is meant for the `Ok` of the route.
+const SUCCESS_STATUS_WARNING: &str = "The status is a success status, errors usually respond with a `4xx` or `5xx` status.";
+
+/// The `actix_web::HttpResponse` builder methods and the status code they respond with.
+const STATUS_NAMES: &[(&str, u16)] = &[
+    ("Continue", 100),
+    ("SwitchingProtocols", 101),
+    ("Processing", 102),
+    ("Ok", 200),
+    ("Created", 201),
+    ("Accepted", 202),
+    ("NonAuthoritativeInformation", 203),
+    ("NoContent", 204),
+    ("ResetContent", 205),
+    ("PartialContent", 206),
+    ("MultiStatus", 207),
+    ("AlreadyReported", 208),
+    ("ImUsed", 226),
+    ("MultipleChoices", 300),
+    ("MovedPermanently", 301),
+    ("Found", 302),
+    ("SeeOther", 303),
+    ("NotModified", 304),
+    ("UseProxy", 305),
+    ("TemporaryRedirect", 307),
+    ("PermanentRedirect", 308),
+    ("BadRequest", 400),
+    ("Unauthorized", 401),
+    ("PaymentRequired", 402),
+    ("Forbidden", 403),
+    ("NotFound", 404),
+    ("MethodNotAllowed", 405),
+    ("NotAcceptable", 406),
+    ("ProxyAuthenticationRequired", 407),
+    ("RequestTimeout", 408),
+    ("Conflict", 409),
+    ("Gone", 410),
+    ("LengthRequired", 411),
+    ("PreconditionFailed", 412),
+    ("PayloadTooLarge", 413),
+    ("UriTooLong", 414),
+    ("UnsupportedMediaType", 415),
+    ("RangeNotSatisfiable", 416),
+    ("ExpectationFailed", 417),
+    ("ImATeapot", 418),
+    ("MisdirectedRequest", 421),
+    ("UnprocessableEntity", 422),
+    ("Locked", 423),
+    ("FailedDependency", 424),
+    ("UpgradeRequired", 426),
+    ("PreconditionRequired", 428),
+    ("TooManyRequests", 429),
+    ("RequestHeaderFieldsTooLarge", 431),
+    ("UnavailableForLegalReasons", 451),
+    ("InternalServerError", 500),
+    ("NotImplemented", 501),
+    ("BadGateway", 502),
+    ("ServiceUnavailable", 503),
+    ("GatewayTimeout", 504),
+    ("VersionNotSupported", 505),
+    ("VariantAlsoNegotiates", 506),
+    ("InsufficientStorage", 507),
+    ("LoopDetected", 508),
+    ("NotExtended", 510),
+    ("NetworkAuthenticationRequired", 511),
+];
+
+/// The status code set with `#[http_status(...)]`.
+#[derive(Clone)]
+enum HttpStatus {
+    /// The name of an `actix_web::HttpResponse` builder method, such as `BadRequest`.
+    Named(Ident),
+    /// A numeric status code, such as `422`.
+    Code(u16),
+    /// A path to a `StatusCode` constant, such as `http::StatusCode::NOT_FOUND`.
+    Path(Path),
+}
 
-    let Data::Enum(data_enum) = &input.data else {
-        panic!("ActixError can only be derived for enums");
-    };
+impl HttpStatus {
+    /// Parses the status code and the modifiers after it from an `http_status` attribute.
+    fn parse(attr: &Attribute) -> syn::Result<(Self, StatusOptions)> {
+        let mut args = attr
+            .parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+            .map_err(|_| {
+                syn::Error::new_spanned(attr, "Expected a status followed by `name = \"value\"` modifiers.")
+            })?
+            .into_iter();
 
-    let transformers = input
-        .attrs
-        .iter()
-        .filter(|attr| attr.path().is_ident("actix_error"))
-        .collect::<Vec<_>>();
+        let status = Self::parse_tokens(
+            args.next().map(|status| status.to_token_stream()).unwrap_or_default(),
+            attr,
+        )?;
 
-    if transformers.len() > 1 {
-        panic!("The `actix_error` attribute is exclusive, only one can exist at the same time.");
-    }
+        let mut options = StatusOptions::default();
+
+        for modifier in args {
+            let Expr::Assign(modifier) = modifier else {
+                return Err(syn::Error::new_spanned(
+                    modifier,
+                    "Expected a `name = \"value\"` modifier.",
+                ));
+            };
 
-    let transformer = transformers.iter().next().and_then(|attr| {
-        if let Ok(Meta::NameValue(meta)) = attr.parse_args() {
-            if meta.path.is_ident("transformer") {
-                if let Expr::Lit(ExprLit {
-                    lit: Lit::Str(lit_str),
+            let literal = match &*modifier.right {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(literal),
                     ..
-                }) = meta.value
-                {
-                    return Some(Ident::new(&lit_str.value(), Span::call_site().into()));
+                }) => literal,
+                value => {
+                    return Err(syn::Error::new_spanned(value, "Expected a string literal value."));
+                }
+            };
+
+            match modifier.left.to_token_stream().to_string().as_str() {
+                "body" if literal.value() == "empty" => {
+                    if options.body_fn.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            &modifier,
+                            "The `body_fn` modifier can't be used with `body = \"empty\"`.",
+                        ));
+                    }
+
+                    options.empty_body = true;
+                }
+                "body" => {
+                    return Err(syn::Error::new_spanned(
+                        &modifier.right,
+                        "The only supported `body` is \"empty\".",
+                    ))
+                }
+                "message" => options.message = Some(literal.clone()),
+                "body_fn" => {
+                    if options.empty_body {
+                        return Err(syn::Error::new_spanned(
+                            &modifier,
+                            "The `body_fn` modifier can't be used with `body = \"empty\"`.",
+                        ));
+                    }
+
+                    options.body_fn = Some(parse_literal::<Path>(literal, "Expected `body_fn` to be a function path.")?);
+                }
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        &modifier.left,
+                        "Unknown `http_status` modifier.",
+                    ));
                 }
             }
         }
 
-        None
-    });
+        Ok((status, options))
+    }
 
-    let mut into_response_arms = Vec::new();
-    let mut into_error_arms = Vec::new();
+    /// Parses the status code from the tokens of an `http_status` attribute,
+    /// errors that don't point to a token point to `origin`.
+    fn parse_tokens(tokens: TokenStream2, origin: &impl ToTokens) -> syn::Result<Self> {
+        if let Ok(ident) = syn::parse2::<Ident>(tokens.clone()) {
+            let name = ident.to_string();
+
+            if STATUS_NAMES.iter().any(|(known, _)| *known == name) {
+                return Ok(Self::Named(ident));
+            }
 
-    for variant in &data_enum.variants {
-        let mut raw_http_code = quote! { InternalServerError };
-        let variant_name = &variant.ident;
+            let closest = STATUS_NAMES
+                .iter()
+                .map(|(known, _)| (levenshtein(known, &name), known))
+                .filter(|(distance, _)| *distance <= 2)
+                .min_by_key(|(distance, _)| *distance);
 
-        for attr in &variant.attrs {
-            if attr.path().is_ident("http_status") {
-                if let Ok(ident) = attr.parse_args::<Ident>() {
-                    raw_http_code = quote! { #ident };
-                }
+            let message = match closest {
+                Some((_, known)) => format!("Unknown status `{name}`, did you mean `{known}`?"),
+                None => format!(
+                    "Unknown status `{name}`, expected an `actix_web::HttpResponse` builder method."
+                ),
+            };
+
+            return Err(syn::Error::new_spanned(ident, message));
+        }
+
+        if let Ok(code) = syn::parse2::<LitInt>(tokens.clone()) {
+            let value = code.base10_parse::<u16>()?;
+
+            if !(100..=599).contains(&value) {
+                return Err(syn::Error::new(
+                    code.span(),
+                    "HTTP status codes must be between 100 and 599.",
+                ));
             }
+
+            return Ok(Self::Code(value));
         }
 
-        let pattern = match &variant.fields {
-            Fields::Unnamed(_) => quote! { Self::#variant_name(..) },
-            Fields::Named(_) => quote! { Self::#variant_name { .. } },
-            Fields::Unit => quote! { Self::#variant_name },
-        };
+        let path = syn::parse2::<Path>(tokens).map_err(|_| {
+            syn::Error::new_spanned(
+                origin,
+                "Expected a status name such as `BadRequest`, a status code such as `400` \
+                or a path such as `http::StatusCode::BAD_REQUEST`.",
+            )
+        })?;
 
-        let response_code = quote! { actix_web::HttpResponse::#raw_http_code() };
-        into_response_arms.push(match transformer {
-            Some(ref tr) => quote! { #pattern => #tr(#response_code, format!("{:#}", self)) },
-            None => quote! { #pattern => #response_code.body(format!("{:#}", self)) },
-        });
+        let mut segments = path.segments.iter().rev();
 
-        let error_ident = format_ident!("Error{}", raw_http_code.to_string());
-        into_error_arms.push(quote! { #pattern => actix_web::error::#error_ident(format!("{:#}", self)) });
+        match (segments.next(), segments.next()) {
+            (Some(_), Some(parent)) if parent.ident == "StatusCode" => Ok(Self::Path(path)),
+            _ => Err(syn::Error::new_spanned(
+                &path,
+                "Expected a path to a `StatusCode` constant such as `http::StatusCode::BAD_REQUEST`.",
+            )),
+        }
     }
 
-    TokenStream::from(quote! {
-        impl ::core::convert::Into<actix_web::HttpResponse> for #enum_name {
-            fn into(self) -> actix_web::HttpResponse {
-                match self {
-                    #(#into_response_arms),*
+    /// Whether the status is known to be in the `2xx` range, which errors shouldn't respond with.
+    fn is_success(&self) -> bool {
+        self.code().is_some_and(|code| (200..300).contains(&code))
+    }
+
+    /// The numeric code of this status, if it's known while expanding.
+    fn code(&self) -> Option<u16> {
+        match self {
+            Self::Named(ident) => STATUS_NAMES
+                .iter()
+                .find(|(name, _)| ident == name)
+                .map(|(_, code)| *code),
+            Self::Code(code) => Some(*code),
+            Self::Path(_) => None,
+        }
+    }
+
+    /// A `StatusCode` expression for this status.
+    fn status_code(&self) -> TokenStream2 {
+        match self {
+            // named statuses are checked against `STATUS_NAMES`, so their code is known.
+            Self::Named(ident) => {
+                let code = self.code().unwrap_or(500);
+
+                quote_spanned! { ident.span() =>
+                    actix_web::http::StatusCode::from_u16(#code).unwrap()
                 }
             }
+            Self::Code(code) => quote! {
+                actix_web::http::StatusCode::from_u16(#code).unwrap()
+            },
+            // the path may come from another version of the `http` crate than the
+            // one `actix_web` uses, so it's converted through the numeric code.
+            Self::Path(path) => quote_spanned! { path.span() =>
+                actix_web::http::StatusCode::from_u16(#path.as_u16()).unwrap()
+            },
         }
+    }
 
-        impl ::core::convert::Into<actix_web::Error> for #enum_name {
-            fn into(self) -> actix_web::Error {
-                match self {
-                    #(#into_error_arms),*
-                }
+    /// An `actix_web::HttpResponseBuilder` expression for this status.
+    fn response_builder(&self) -> TokenStream2 {
+        match self {
+            Self::Named(ident) => quote! { actix_web::HttpResponse::#ident() },
+            _ => {
+                let status = self.status_code();
+                quote! { actix_web::HttpResponse::build(#status) }
             }
         }
-    })
+    }
+
+    /// An `actix_web::Error` expression for this status with a message.
+    fn error(&self, message: &TokenStream2) -> TokenStream2 {
+        // `actix_web::error::Error*` helpers only exist for error statuses,
+        // so every status goes through `InternalError` as those helpers do.
+        let status = self.status_code();
+        quote! { actix_web::error::InternalError::new(#message, #status).into() }
+    }
 }
 
-/// This macro attribute wraps actix http route handlers, due to
-/// the limitation where the attribute definition order is undefined
-/// this macro also wraps the actix_web::{get, post, put, patch, delete, options, trace}
-/// macros.
-///
-/// The usage in a route handler is the following.
-///
-/// ```ignore
-/// use actix_error_proc::{ActixError, Error, HttpResult}; // Error is a thiserror re export.
-/// use crate::models::user::User;
-/// use actix_web::{main, App, HttpServer}
-/// use serde_json::{from_slice, Error as JsonError};
-/// use std::io::Error as IoError;
-///
-/// // assuming we have a wrapped enum
-/// #[derive(ActixError, Error, Debug)]
-/// enum SomeError {
-///     #[error("The body could not be parsed.")]
-///     #[status_code(BadRequest)]
-///     InvalidBody(#[from] JsonError)
-/// }
-///
-/// #[proof_route(post("/users"))]
-/// async fn some_route(body: Bytes) -> HttpResult<SomeError> {
-///     let user: User = from_slice(body)?; // notice the use of `?`.
-///
-///     // Do something with the user.
-///
-///     Ok(HttpResponse::NoContent()) // return Ok if everything went fine.
-/// }
-///
-/// async fn main() -> IoError {
-///     HttpServer::new(|| {
-///         App::new()
-///             .service(some_route) // we can register the route normally.
-///     })
-///         .bind(("0.0.0.0", 8080))?
-///         .run()
-///         .await
-/// }
-/// ```
-///
-/// There is an extra attribute we can add to route collectors to override
-/// it's error status code, in the case we don't want the original status code
-/// or we didn't create the collector and the original error does not match our
-/// expectations we can use `#[or]`, which lets us specify an error branch
-/// from any type instance that implements `Into<HttpResponse>`.
-///
-/// ```ignore
-/// #[proof_route(post("/"))]
-/// async fn route(#[or(SomeError::InvalidUser)] user: Json<User>) // ...
-/// ```
-///
-/// In this case if `Json<User>` fails while collecting from the http request
-/// whatever `<SomeError::InvalidUser as Into<actix_web::HttpResponse>>.into()` returns
-/// will be passed directly as a response for the route.
-///
-/// If you don't add the attribute, the request will be collected as normal and in the
-/// case of any error the original error implementation for that collector will
-/// be applied.
-#[proc_macro_attribute]
-pub fn proof_route(attr: TokenStream, item: TokenStream) -> TokenStream {
-    let args = parse_macro_input!(attr as ExprCall);
-    let mut item = parse_macro_input!(item as ItemFn);
+/// A shape an error can have once it's converted, which
+/// is every variant of an enum or the whole struct.
+struct ErrorUnit<'a> {
+    /// The name of the variant or struct.
+    ident: &'a Ident,
+    /// The path used to match and build the unit, `Self::Variant` or `Self`.
+    path: TokenStream2,
+    /// The attributes of the variant or struct.
+    attrs: &'a [Attribute],
+    fields: &'a Fields,
+    options: VariantOptions,
+}
 
-    let original_name = item.sig.ident.clone();
-    let renamed_ident = Ident::new(
-        &format!("__proof_route_{original_name}"),
-        original_name.span(),
-    );
-    item.sig.ident = renamed_ident.clone();
+impl ErrorUnit<'_> {
+    /// A pattern that matches the unit ignoring its fields.
+    fn pattern(&self) -> TokenStream2 {
+        let path = &self.path;
+
+        match self.fields {
+            Fields::Unnamed(_) => quote! { #path(..) },
+            Fields::Named(_) => quote! { #path { .. } },
+            Fields::Unit => quote! { #path },
+        }
+    }
 
-    let allowed_methods = ["get", "put", "post", "delete", "patch", "options", "trace"];
+    /// A pattern that binds the only field of the unit to `inner`.
+    fn inner_pattern(&self) -> Option<(TokenStream2, &Field)> {
+        let path = &self.path;
 
-    let method = if let Expr::Path(path) = *args.func {
-        let method = path.to_token_stream().to_string();
+        match self.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                Some((quote! { #path(inner) }, &fields.unnamed[0]))
+            }
+            Fields::Named(fields) if fields.named.len() == 1 => {
+                let field = &fields.named[0];
+                let ident = &field.ident;
 
-        if allowed_methods.contains(&method.as_str()) {
-            Ident::new(&method, path.span())
-        } else {
-            panic!("The method is not a valid HTTP method.");
+                Some((quote! { #path { #ident: inner } }, field))
+            }
+            _ => None,
         }
-    } else {
-        panic!("Expected a path.");
-    };
+    }
+}
 
-    let path = if let Some(arg) = args.args.first() {
-        if let Expr::Lit(ExprLit {
-            lit: Lit::Str(path),
-            ..
-        }) = arg
-        {
-            path
-        } else {
-            panic!("Expected a string literal argument.");
+/// The attribute with the given name, set at most once since a repeated
+/// attribute would silently replace the first one.
+fn single_attr<'a>(attrs: &'a [Attribute], name: &str) -> syn::Result<Option<&'a Attribute>> {
+    let mut found = attrs.iter().filter(|attr| attr.path().is_ident(name));
+    let attr = found.next();
+
+    if let Some(repeated) = found.next() {
+        return Err(syn::Error::new_spanned(
+            repeated,
+            format!("The `{name}` attribute is set more than once."),
+        ));
+    }
+
+    Ok(attr)
+}
+
+/// Converts a `PascalCase` name to `snake_case`, keeping acronyms as a single word.
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<_>>();
+    let mut result = String::new();
+
+    for (index, &char) in chars.iter().enumerate() {
+        if char.is_uppercase() && index > 0 {
+            let previous = chars[index - 1];
+            let next_lowercase = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+
+            if previous.is_lowercase() || previous.is_numeric() || (previous.is_uppercase() && next_lowercase) {
+                result.push('_');
+            }
         }
-    } else {
-        panic!("Expected at least one argument.");
-    };
 
-    if args.args.len() > 1 {
-        panic!("Expected only one argument.");
+        result.extend(char.to_lowercase());
     }
 
-    let mut extractions = Vec::new();
-    let mut renamed_vars = Vec::new();
+    result
+}
 
-    for (idx, arg) in item.sig.inputs.iter_mut().enumerate() {
-        if let FnArg::Typed(pat_type) = arg {
-            let var_pat = &pat_type.pat;
-            let var_name = quote::format_ident!("__arg_{idx}", span = var_pat.span());
-            let ty = &pat_type.ty;
+/// The modifiers set after the status in `#[http_status(...)]`.
+#[derive(Default)]
+struct StatusOptions {
+    /// Whether the response has no body, set with `body = "empty"`.
+    empty_body: bool,
+    /// A static message sent instead of the formatted error, set with `message = "..."`.
+    message: Option<LitStr>,
+    /// A function building the body from the error, set with `body_fn = "path"`.
+    body_fn: Option<Path>,
+}
 
-            let mut error_variant = None;
+/// The options set in the `actix_error` attribute of an enum variant.
+#[derive(Default)]
+struct VariantOptions {
+    /// The order in which the variant is matched, higher goes first.
+    priority: i64,
+    /// Whether merged errors without their own variant are converted into this one.
+    catch_all: bool,
+    /// Whether clients may retry the request that failed with this variant.
+    retriable: bool,
+    /// The error type the conversion of the variant is delegated to.
+    delegate_to: Option<Type>,
+}
 
-            pat_type.attrs.retain(|attr| {
-                if attr.path().is_ident("or") {
-                    error_variant = Some(
-                        attr.parse_args::<Expr>()
-                            .expect("Expected an enum variant.")
-                    );
-                }
+/// The number of single character edits that turn `from` into `to`.
+fn levenshtein(from: &str, to: &str) -> usize {
+    let to = to.chars().collect::<Vec<_>>();
+    let mut previous = (0..=to.len()).collect::<Vec<_>>();
 
-                error_variant.is_none()
-            });
+    for (i, from_char) in from.chars().enumerate() {
+        let mut current = vec![i + 1];
 
-            let error_extractor = if let Some(error) = error_variant {
-                quote! { Err(_) => return #error.into() }
-            } else {
-                quote! { Err(err) => return err.into() }
+        for (j, to_char) in to.iter().enumerate() {
+            let substitution = previous[j] + usize::from(from_char != *to_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[to.len()]
+}
+
+/// Calls a transformer through a function pointer, so a function with another
+/// signature is reported once on the transformer with the expected signature,
+/// instead of the argument and type errors of a direct call in every arm.
+fn transformer_call(
+    transformer: &Path,
+    asynchronous: bool,
+    builder: &TokenStream2,
+    message: &TokenStream2,
+) -> TokenStream2 {
+    if asynchronous {
+        quote_spanned! { transformer.span() => {
+            fn async_transformer<F: ::core::future::Future<Output = actix_web::HttpResponse>>(
+                transformer: fn(actix_web::HttpResponseBuilder, ::std::string::String) -> F,
+            ) -> fn(actix_web::HttpResponseBuilder, ::std::string::String) -> F {
+                transformer
+            }
+
+            async_transformer(#transformer)(#builder, #message).await
+        }}
+    } else {
+        quote_spanned! { transformer.span() => {
+            let transformer: fn(actix_web::HttpResponseBuilder, ::std::string::String) -> actix_web::HttpResponse
+                = #transformer;
+
+            transformer(#builder, #message)
+        }}
+    }
+}
+
+/// Emits a compiler warning pointing to `origin`, proc macros can't emit
+/// warnings in stable rust, so this uses a deprecated item instead.
+fn warning(origin: &impl Spanned, message: &str) -> TokenStream2 {
+    quote_spanned! { origin.span() =>
+        const _: () = {
+            #[deprecated(note = #message)]
+            const WARNING: () = ();
+            WARNING
+        };
+    }
+}
+
+/// The `tracing::Level` constant for a `log_level` or `#[log(level = "...")]` value.
+fn tracing_level(level: &LitStr) -> syn::Result<Ident> {
+    match level.value().as_str() {
+        "error" | "warn" | "info" | "debug" | "trace" => Ok(Ident::new(&level.value().to_uppercase(), level.span())),
+        _ => Err(syn::Error::new_spanned(
+            level,
+            "Expected the level to be \"error\", \"warn\", \"info\", \"debug\" or \"trace\".",
+        )),
+    }
+}
+
+/// Reads the options of an enum variant from its `actix_error` attribute.
+fn variant_options(variant: &Variant) -> syn::Result<VariantOptions> {
+    let mut variant_options = VariantOptions::default();
+
+    for attr in variant.attrs.iter().filter(|attr| attr.path().is_ident("actix_error")) {
+        let options = attr
+            .parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            .map_err(|error| syn::Error::new(error.span(), "Expected a comma separated list of options."))?;
+
+        for option in options {
+            let option = match option {
+                Meta::Path(flag) if flag.is_ident("catch_all") => {
+                    variant_options.catch_all = true;
+                    continue;
+                }
+                Meta::Path(flag) if flag.is_ident("retriable") => {
+                    variant_options.retriable = true;
+                    continue;
+                }
+                Meta::NameValue(MetaNameValue {
+                    path,
+                    value: Expr::Lit(ExprLit {
+                        lit: Lit::Str(delegate),
+                        ..
+                    }),
+                    ..
+                }) if path.is_ident("delegate_to") => {
+                    variant_options.delegate_to = Some(parse_literal::<Type>(
+                        &delegate,
+                        "Expected `delegate_to` to be an error type.",
+                    )?);
+                    continue;
+                }
+                Meta::NameValue(option) if option.path.is_ident("priority") => option,
+                option => {
+                    return Err(syn::Error::new_spanned(
+                        option,
+                        "Unknown variant `actix_error` option.",
+                    ));
+                }
             };
 
-            extractions.push(quote! {
-                let #var_name: #ty = match <#ty as actix_web::FromRequest>::from_request(&req, &mut payload).await {
-                    Ok(v) => v,
-                    #error_extractor,
-                };
-            });
+            let Expr::Lit(ExprLit {
+                lit: Lit::Int(value),
+                ..
+            }) = &option.value
+            else {
+                return Err(syn::Error::new_spanned(
+                    &option.value,
+                    "Expected an integer literal for `priority`.",
+                ));
+            };
 
-            renamed_vars.push(var_name.clone());
+            variant_options.priority = value
+                .base10_parse()
+                .map_err(|_| syn::Error::new_spanned(value, "Expected `priority` to fit in an i64."))?;
         }
     }
 
-    TokenStream::from(quote! {
-        #[actix_web::#method(#path)]
-        async fn #original_name(req: actix_web::HttpRequest, payload: actix_web::web::Payload) -> impl actix_web::Responder {
-            #[doc(hidden)]
-            #item
+    Ok(variant_options)
+}
 
-            #[allow(unused)]
-            #[doc(hidden)]
-            let mut payload = payload.into_inner();
+/// Reads the value of a route flag, which can be set by name
+/// only or be assigned a boolean literal.
+fn flag_value(value: Option<Expr>) -> syn::Result<bool> {
+    match value {
+        None => Ok(true),
+        Some(Expr::Lit(ExprLit {
+            lit: Lit::Bool(value),
+            ..
+        })) => Ok(value.value),
+        Some(value) => Err(syn::Error::new_spanned(value, "Expected a boolean literal for the flag.")),
+    }
+}
 
-            #(#extractions)*
+/// Parses the contents of a string literal option, errors point to the literal.
+fn parse_literal<T: syn::parse::Parse>(literal: &LitStr, message: &str) -> syn::Result<T> {
+    literal
+        .parse::<T>()
+        .map_err(|_| syn::Error::new_spanned(literal, message))
+}
 
-            match #renamed_ident(#(#renamed_vars),*).await {
-                ::core::result::Result::Ok(r) => r,
-                ::core::result::Result::Err(r) => r.into()
+/// Finds the error type of a route return type, which is the
+/// last generic argument of `HttpResult<E>` or `Result<T, E>`.
+fn result_error_type(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+
+    let segment = path.path.segments.last()?;
+
+    if segment.ident != "HttpResult" && segment.ident != "Result" {
+        return None;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+
+    match args.args.last()? {
+        GenericArgument::Type(error) => Some(error),
+        _ => None,
+    }
+}
+
+/// Whether a route return type is `HttpResult` or a `Result` of an `HttpResponse`,
+/// the check is done on the names since the types can't be resolved by macros.
+fn route_result(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+
+    let Some(segment) = path.path.segments.last() else {
+        return false;
+    };
+
+    if segment.ident == "HttpResult" {
+        return true;
+    }
+
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return false;
+    };
+
+    segment.ident == "Result"
+        && matches!(
+            args.args.first(),
+            Some(GenericArgument::Type(Type::Path(response)))
+                if response.path.segments.last().is_some_and(|segment| segment.ident == "HttpResponse")
+        )
+}
+
+/// Whether a route return type is a bare `HttpResponse` or `impl Responder`.
+fn route_response(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "HttpResponse"),
+        Type::ImplTrait(bounds) => bounds.bounds.iter().any(|bound| {
+            matches!(
+                bound,
+                TypeParamBound::Trait(bound)
+                    if bound.path.segments.last().is_some_and(|segment| segment.ident == "Responder")
+            )
+        }),
+        _ => false,
+    }
+}
+
+/// Whether a route return type can't fail, which is `HttpResult`
+/// without arguments or a result with an `Infallible` error.
+fn infallible_result(ty: &Type) -> bool {
+    if let Type::Path(path) = ty {
+        if let Some(segment) = path.path.segments.last() {
+            if segment.ident == "HttpResult" && segment.arguments.is_none() {
+                return true;
             }
         }
-    })
+    }
+
+    matches!(
+        result_error_type(ty),
+        Some(Type::Path(error)) if error.path.segments.last().is_some_and(|segment| segment.ident == "Infallible")
+    )
+}
+
+/// The methods of a `route("/path", methods = "GET POST")` call, which
+/// are separated by spaces or commas.
+fn route_methods(call: &ExprCall, allowed_methods: &[&str]) -> syn::Result<Vec<Ident>> {
+    let Some(Expr::Assign(assign)) = call.args.iter().nth(1) else {
+        return Err(syn::Error::new_spanned(
+            call,
+            "Expected the methods of the route such as `route(\"/\", methods = \"GET POST\")`.",
+        ));
+    };
+
+    let (Expr::Path(name), Expr::Lit(ExprLit { lit: Lit::Str(list), .. })) = (&*assign.left, &*assign.right) else {
+        return Err(syn::Error::new_spanned(assign, "Expected `methods = \"GET POST\"`."));
+    };
+
+    if !name.path.is_ident("methods") {
+        return Err(syn::Error::new_spanned(name, "Expected `methods = \"GET POST\"`."));
+    }
+
+    let mut methods = Vec::<Ident>::new();
+
+    for method in list.value().split([' ', ',']).filter(|method| !method.is_empty()) {
+        let name = method.to_lowercase();
+
+        if !allowed_methods.contains(&name.as_str()) {
+            return Err(syn::Error::new_spanned(
+                list,
+                format!("The method `{method}` is not a valid HTTP method."),
+            ));
+        }
+
+        if methods.iter().any(|listed| *listed == name) {
+            return Err(syn::Error::new_spanned(
+                list,
+                format!("The method `{method}` is listed more than once."),
+            ));
+        }
+
+        methods.push(Ident::new(&name, list.span()));
+    }
+
+    if methods.is_empty() {
+        return Err(syn::Error::new_spanned(list, "Expected at least one method."));
+    }
+
+    Ok(methods)
+}
+
+/// Whether a parameter type is the `Payload` extractor of actix_web, which never fails
+/// since it only hands over the stream, it's matched by its path such as `web::Payload`
+/// because an imported `Payload` may be another extractor.
+fn payload_extractor(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+
+    let segments = path
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.arguments.is_none().then(|| segment.ident.to_string()))
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default();
+
+    path.qself.is_none()
+        && matches!(
+            segments.iter().map(String::as_str).collect::<Vec<_>>()[..],
+            ["web", "Payload"] | ["actix_web", "web", "Payload"]
+        )
+}
+
+/// Formats a type as it would be written, without the spaces
+/// a token stream puts between every token.
+fn type_string(ty: &Type) -> String {
+    let tokens = ty.to_token_stream().to_string();
+    let chars = tokens.chars().collect::<Vec<_>>();
+    let is_word = |c: &char| c.is_alphanumeric() || *c == '_';
+
+    chars
+        .iter()
+        .enumerate()
+        .filter(|(idx, c)| {
+            **c != ' '
+                || (chars.get(idx.wrapping_sub(1)).is_some_and(is_word)
+                    && chars.get(idx + 1).is_some_and(is_word))
+        })
+        .map(|(_, c)| c)
+        .collect()
+}
+
+/// Whether the variants of a route error can be listed, which errors without variants,
+/// such as `Infallible`, or depending on the parameters of a generic handler can't.
+fn listed_error(ty: &Type, generics: &syn::Generics) -> bool {
+    fn mentions(tokens: TokenStream2, params: &[&Ident]) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Ident(ident) => params.contains(&&ident),
+            proc_macro2::TokenTree::Group(group) => mentions(group.stream(), params),
+            _ => false,
+        })
+    }
+
+    let Type::Path(path) = ty else {
+        return false;
+    };
+
+    let params = generics.type_params().map(|param| &param.ident).collect::<Vec<_>>();
+
+    path.qself.is_none()
+        && path.path.segments.last().is_some_and(|segment| segment.ident != "Infallible")
+        && !mentions(ty.to_token_stream(), &params)
 }
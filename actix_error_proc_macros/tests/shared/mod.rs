@@ -1,16 +1,22 @@
 use actix_web::HttpResponse;
 
+// not every test returns errors.
+#[allow(dead_code)]
 pub type HttpResult<E> = Result<HttpResponse, E>;
 
 #[macro_export]
 macro_rules! web_server {
-    ($route:expr) => {{
+    ($route:expr) => {
+        web_server!($route, wrap = actix_web::middleware::Identity::default())
+    };
+
+    ($route:expr, wrap = $middleware:expr) => {{
         let (tx_server, rx_server) = std::sync::mpsc::channel();
         let (tx_address, rx_address) = std::sync::mpsc::channel();
 
         let server = std::thread::spawn(move || {
             let sys = actix_web::rt::System::new();
-            let srv = actix_web::HttpServer::new(move || actix_web::App::new().service($route))
+            let srv = actix_web::HttpServer::new(move || actix_web::App::new().wrap($middleware).service($route))
                 .bind(("127.0.0.1", 0))
                 .unwrap();
 
@@ -0,0 +1,6 @@
+#[test]
+fn should_fail_to_compile() {
+    let cases = trybuild::TestCases::new();
+
+    cases.compile_fail("tests/ui/*.rs");
+}
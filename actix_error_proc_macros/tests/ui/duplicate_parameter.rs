@@ -0,0 +1,16 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test
+}
+
+#[proof_route(get("/{id}"))]
+async fn test_route(id: actix_web::web::Path<u32>, id: actix_web::web::Query<u32>) -> Result<actix_web::HttpResponse, TestError> {
+    Ok(actix_web::HttpResponse::Ok().body(id.to_string()))
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+fn not_found_body(_: &TestError) -> serde_json::Value {
+    serde_json::json!({})
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("not found")]
+    #[http_status(NotFound)]
+    #[http_json_body(key = "message", object = "not_found_body")]
+    NotFound
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{web::Path, HttpResponse};
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(NotFound)]
+    Test
+}
+
+#[proof_route(get("/{id}"))]
+async fn test_route(#[or_default] id: Path<u32>) -> Result<HttpResponse, TestError> {
+    Ok(HttpResponse::Ok().body(id.to_string()))
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(json_body, code_field = "code")]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    #[error_code(1)]
+    #[error_code(2)]
+    Test
+}
+
+fn main() {}
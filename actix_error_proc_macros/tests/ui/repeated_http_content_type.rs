@@ -0,0 +1,13 @@
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    #[http_content_type("text/plain")]
+    #[http_content_type("application/problem+json")]
+    Test
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("moved")]
+    #[http_status(MovedPermanently)]
+    #[http_location("/new")]
+    #[http_location("/newer")]
+    Moved
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("slow down")]
+    #[http_status(TooManyRequests)]
+    #[http_retry_after(30)]
+    #[http_retry_after(60)]
+    RateLimited
+}
+
+fn main() {}
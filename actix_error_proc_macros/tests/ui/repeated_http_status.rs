@@ -0,0 +1,12 @@
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    #[http_status(NotFound)]
+    Test
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use std::net::IpAddr;
+use thiserror::Error;
+
+// the generated code names the runtime crate, which declares this trait.
+mod actix_error_proc {
+    pub trait RetryBudget {
+        fn consume(&self, client: std::net::IpAddr) -> bool;
+    }
+}
+
+struct TestBudget;
+
+impl actix_error_proc::RetryBudget for TestBudget {
+    fn consume(&self, _: IpAddr) -> bool {
+        true
+    }
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(ServiceUnavailable)]
+    Test
+}
+
+#[proof_route(get("/"), retry_budget = "TestBudget")]
+async fn test_route() -> Result<HttpResponse, TestError> {
+    Err(TestError::Test)
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use actix_error_proc_macros::proof_route;
+
+#[proof_route(get("/"))]
+async fn test_route() -> String {
+    String::from("test")
+}
+
+fn main() {}
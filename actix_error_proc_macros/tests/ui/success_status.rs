@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(Ok)]
+    Test
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use actix_error_proc_macros::proof_route;
+
+#[proof_route(get("/"))]
+fn test_route() -> actix_web::HttpResponse {
+    actix_web::HttpResponse::Ok().finish()
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use actix_error_proc_macros::ActixError;
+use actix_web::{HttpResponse, HttpResponseBuilder};
+use thiserror::Error;
+
+fn transformer(mut builder: HttpResponseBuilder) -> HttpResponse {
+    builder.finish()
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(transformer = "transformer")]
+enum TestError {
+    #[error("test")]
+    Test,
+
+    #[error("test2")]
+    Test2
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use actix_error_proc_macros::{unify_errors, ActixError};
+use thiserror::Error;
+
+// the generated code names the runtime crate, which re-exports this.
+mod actix_error_proc {
+    pub use actix_error_proc_macros::__unify_errors;
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(describe)]
+enum AuthError {
+    #[error("not found")]
+    #[http_status(NotFound)]
+    NotFound
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(describe)]
+enum DatabaseError {
+    #[error("not found")]
+    #[http_status(NotFound)]
+    NotFound
+}
+
+unify_errors!(TestError { AuthError, DatabaseError });
+
+fn main() {}
@@ -0,0 +1,11 @@
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadReqest)]
+    Test
+}
+
+fn main() {}
@@ -0,0 +1,48 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use thiserror::Error;
+use crate::shared::HttpResult;
+use reqwest::{Client, Method, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[proof_route(any("/"), inject_request = "req")]
+async fn test_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(req.method().to_string()))
+}
+
+#[test]
+async fn should_respond_to_any_method() {
+    let (thread, server, address) = web_server!(test_route);
+
+    // the route has no method guard, so methods without a constant reach it too.
+    let purge = Method::from_bytes(b"PURGE").unwrap();
+
+    for method in [Method::GET, Method::POST, Method::DELETE, purge] {
+        let result = Client::new()
+            .request(method.clone(), &address)
+            .send()
+            .await
+            .expect("Error while making the request.");
+
+        assert_eq!(result.status(), StatusCode::OK);
+
+        let text = result
+            .text()
+            .await
+            .expect("Error while reading response body.");
+
+        assert_eq!(text, method.as_str());
+    }
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
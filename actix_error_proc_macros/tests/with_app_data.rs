@@ -0,0 +1,81 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::web::{scope, Data};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+struct Counter {
+    start: u32
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("The counter is not available.")]
+    #[http_status(ServiceUnavailable)]
+    MissingCounter
+}
+
+#[proof_route(get("/"))]
+async fn test_route(counter: Data<Counter>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(counter.start.to_string()))
+}
+
+#[proof_route(get("/"))]
+async fn test_or_route(#[or(TestError::MissingCounter)] counter: Data<Counter>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(counter.start.to_string()))
+}
+
+#[test]
+async fn should_read_app_data() {
+    let (thread, server, address) = web_server!(
+        scope("").app_data(Data::new(Counter { start: 10 })).service(test_route)
+    );
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "10");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_respond_500_on_missing_app_data() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_use_or_on_missing_app_data() {
+    let (thread, server, address) = web_server!(test_or_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,59 @@
+use actix_web::{HttpResponse, HttpResponseBuilder};
+use actix_web::rt::time::sleep;
+use crate::shared::HttpResult;
+use core::time::Duration;
+use thiserror::Error;
+use actix_error_proc_macros::{proof_route, ActixError};
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+async fn transformer(mut req: HttpResponseBuilder, fmt: String) -> HttpResponse {
+    sleep(Duration::from_millis(1)).await;
+
+    req
+        .append_header(("format", fmt))
+        .body("async")
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(async_transformer = "transformer")]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test
+}
+
+#[proof_route(get("/"), async_transformer)]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[test]
+async fn should_await_the_transformer() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let header = result
+        .headers()
+        .get("format")
+        .expect("Missing format header.");
+
+    assert_eq!(header, "test");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "async");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,44 @@
+use actix_error_proc_macros::ActixError;
+use actix_web::HttpResponse;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use thiserror::Error;
+use tokio::test;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(backtrace)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestPlainError {
+    #[error("test2")]
+    Test2
+}
+
+#[test]
+async fn should_add_backtrace_header() {
+    let response: HttpResponse = TestError::Test.into();
+    let header = response.headers().get("x-backtrace");
+
+    // release builds only capture it when `RUST_BACKTRACE` enables it.
+    if cfg!(debug_assertions) || Backtrace::capture().status() == BacktraceStatus::Captured {
+        let header = header
+            .expect("Missing backtrace header.")
+            .to_str()
+            .unwrap();
+
+        assert!(header.len() <= 4096);
+        assert!(!header.contains('\n'));
+    } else {
+        assert!(header.is_none());
+    }
+}
+
+#[test]
+async fn should_not_add_backtrace_header() {
+    let response: HttpResponse = TestPlainError::Test2.into();
+
+    assert!(response.headers().get("x-backtrace").is_none());
+}
@@ -0,0 +1,83 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn plain_body(_: &TestError) -> &'static str {
+    "plain"
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(body_encoding = "base64")]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test,
+
+    #[error("plain")]
+    #[http_status(NotFound, body_fn = "plain_body")]
+    Plain
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[proof_route(get("/"))]
+async fn test_plain_route() -> HttpResult<TestError> {
+    Err(TestError::Plain)
+}
+
+#[test]
+async fn should_encode_body_as_base64() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let header = result
+        .headers()
+        .get("Content-Transfer-Encoding")
+        .expect("Missing Content-Transfer-Encoding header.");
+
+    assert_eq!(header, "base64");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "dGVzdA==");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_not_mark_body_fn_bodies_as_base64() {
+    let (thread, server, address) = web_server!(test_plain_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+    assert!(result.headers().get("Content-Transfer-Encoding").is_none());
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "plain");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
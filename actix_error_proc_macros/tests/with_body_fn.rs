@@ -0,0 +1,54 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn missing_field_body(error: &TestError) -> String {
+    match error {
+        TestError::MissingField(field) => format!("missing {field}"),
+    }
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("The field {0} is missing.")]
+    #[http_status(BadRequest, body_fn = "missing_field_body")]
+    MissingField(String)
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::MissingField("name".into()))
+}
+
+#[test]
+async fn should_build_body_with_function() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "missing name");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_build_error_body_with_function() {
+    let error: actix_web::Error = TestError::MissingField("name".into()).into();
+
+    assert_eq!(error.to_string(), "The field name is missing.");
+    assert_eq!(error.error_response().status().as_u16(), 400);
+}
@@ -0,0 +1,42 @@
+use std::error::Error as StdError;
+
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("The database failed: {0}")]
+    #[http_status(ServiceUnavailable)]
+    Database(Box<dyn StdError + Send + Sync>)
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Database("connection refused".into()))
+}
+
+#[test]
+async fn should_respond_with_boxed_error() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "The database failed: connection refused");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
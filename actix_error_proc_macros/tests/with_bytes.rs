@@ -0,0 +1,62 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::web::Bytes;
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use reqwest::{Client, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("unreachable")]
+    Unreachable
+}
+
+#[proof_route(post("/"))]
+async fn test_route(body: Bytes) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(body.len().to_string()))
+}
+
+#[test]
+async fn should_extract_bytes() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .body("some bytes")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "10");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_reject_big_bytes() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .body(vec![0u8; 512 * 1024])
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,67 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use reqwest::{get, StatusCode};
+use tokio::test;
+use thiserror::Error;
+use crate::shared::HttpResult;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+pub enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[proof_route(get("/panic"), catch_panic)]
+async fn panic_route() -> HttpResult<TestError> {
+    panic!("oops");
+}
+
+#[proof_route(get("/ok"), catch_panic)]
+async fn ok_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body("ok"))
+}
+
+#[test]
+async fn should_catch_panic() {
+    let (thread, server, address) = web_server!(panic_route);
+
+    let result = get(format!("{address}panic"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "internal error");
+
+    // the worker is still alive after the panic.
+    let result = get(format!("{address}panic"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_not_change_successful_routes() {
+    let (thread, server, address) = web_server!(ok_route);
+
+    let result = get(format!("{address}ok"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
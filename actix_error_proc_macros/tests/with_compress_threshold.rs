@@ -0,0 +1,76 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{middleware::Compress, web::Query, HttpResponse};
+use crate::shared::HttpResult;
+use serde::Deserialize;
+use thiserror::Error;
+use reqwest::{Client, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("invalid size")]
+    #[http_status(BadRequest)]
+    InvalidSize
+}
+
+#[derive(Deserialize)]
+struct Body {
+    size: usize
+}
+
+#[proof_route(get("/"), compress_threshold = 16)]
+async fn test_route(#[or(TestError::InvalidSize)] body: Query<Body>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body("a".repeat(body.size)))
+}
+
+#[test]
+async fn should_only_compress_above_threshold() {
+    let (thread, server, address) = web_server!(test_route, wrap = Compress::default());
+
+    for (size, expected) in [(8, "identity"), (16, "identity"), (17, "gzip"), (32, "gzip")] {
+        let result = Client::new()
+            .get(format!("{address}?size={size}"))
+            .header("Accept-Encoding", "gzip")
+            .send()
+            .await
+            .expect("Error while making the request.");
+
+        assert_eq!(result.status(), StatusCode::OK);
+
+        let encoding = result
+            .headers()
+            .get("Content-Encoding")
+            .expect("Missing Content-Encoding header.");
+
+        assert_eq!(encoding, expected);
+    }
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_check_threshold_on_extraction_errors() {
+    let (thread, server, address) = web_server!(test_route, wrap = Compress::default());
+
+    let result = Client::new()
+        .get(address)
+        .header("Accept-Encoding", "gzip")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let encoding = result
+        .headers()
+        .get("Content-Encoding")
+        .expect("Missing Content-Encoding header.");
+
+    assert_eq!(encoding, "identity");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,76 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    #[http_content_type("text/markdown")]
+    Test
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(json_body)]
+enum TestJsonError {
+    #[error("test2")]
+    #[http_content_type("application/problem+json")]
+    Test2
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[proof_route(get("/"))]
+async fn test2_route() -> HttpResult<TestJsonError> {
+    Err(TestJsonError::Test2)
+}
+
+#[test]
+async fn should_set_content_type() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(result.headers().get("content-type").unwrap(), "text/markdown");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "test");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_override_json_content_type() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.headers().get("content-type").unwrap(), "application/problem+json");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, r#"{"error":"test2"}"#);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,76 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::http::StatusCode as ActixStatusCode;
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(default_status = "UnprocessableEntity")]
+enum TestError {
+    #[error("test")]
+    Test,
+
+    #[error("test2")]
+    #[http_status(Conflict)]
+    Test2
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(default_status = 451)]
+#[error("test struct")]
+struct TestStructError;
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[proof_route(get("/"))]
+async fn test2_route() -> HttpResult<TestError> {
+    Err(TestError::Test2)
+}
+
+#[test]
+async fn should_use_default_status() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "test");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_prefer_variant_status() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::CONFLICT);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_use_default_status_on_structs() {
+    let error: actix_web::Error = TestStructError.into();
+
+    assert_eq!(error.as_response_error().status_code(), ActixStatusCode::UNAVAILABLE_FOR_LEGAL_REASONS);
+}
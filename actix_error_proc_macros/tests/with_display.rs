@@ -0,0 +1,46 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Debug)]
+#[actix_error(with_display = "Error: {self:?}")]
+enum TestError {
+    #[http_status(NotFound)]
+    NotFound,
+    Invalid(#[allow(unused)] u32)
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::NotFound)
+}
+
+#[test]
+async fn should_format_with_template() {
+    assert_eq!(TestError::NotFound.to_string(), "Error: NotFound");
+    assert_eq!(TestError::Invalid(1).to_string(), "Error: Invalid(1)");
+}
+
+#[test]
+async fn should_respond_with_template() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "Error: NotFound");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,34 @@
+use actix_error_proc_macros::ActixError;
+use actix_web::{http::StatusCode, HttpResponse};
+use std::sync::Mutex;
+use thiserror::Error;
+
+static EVENTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+trait ErrorEvent<E> {
+    fn emit(error: E);
+}
+
+struct ErrorOccurred;
+
+impl ErrorEvent<TestError> for ErrorOccurred {
+    fn emit(error: TestError) {
+        EVENTS.lock().unwrap().push(error.to_string());
+    }
+}
+
+#[derive(ActixError, Error, Debug, Clone)]
+#[actix_error(emit_event = "ErrorOccurred")]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test
+}
+
+#[test]
+fn should_emit_event_before_response() {
+    let response: HttpResponse = TestError::Test.into();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(*EVENTS.lock().unwrap(), ["test"]);
+}
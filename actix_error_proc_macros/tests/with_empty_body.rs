@@ -0,0 +1,78 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{HttpResponse, HttpResponseBuilder};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn transformer(mut req: HttpResponseBuilder, fmt: String) -> HttpResponse {
+    req
+        .append_header(("format-length", fmt.len()))
+        .finish()
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(NoContent, body = "empty")]
+    Test,
+
+    #[error("test2")]
+    #[http_status(BadRequest, body = "empty")]
+    #[http_transformer = "transformer"]
+    Test2
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[proof_route(get("/"))]
+async fn test2_route() -> HttpResult<TestError> {
+    Err(TestError::Test2)
+}
+
+#[test]
+async fn should_return_empty_body() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NO_CONTENT);
+
+    let body = result
+        .bytes()
+        .await
+        .expect("Error while reading response body.");
+
+    assert!(body.is_empty());
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_pass_empty_message_to_transformer() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let header = result
+        .headers()
+        .get("format-length")
+        .expect("Missing format-length header.");
+
+    assert_eq!(header, "0");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
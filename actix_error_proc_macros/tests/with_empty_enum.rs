@@ -0,0 +1,33 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(with_status_fn, impl_try_from_status, retriable)]
+enum Never {}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<Never> {
+    Ok(HttpResponse::Ok().body("ok"))
+}
+
+#[test]
+async fn should_derive_for_empty_enums() {
+    assert!(Never::try_from(500).is_err());
+
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,83 @@
+use actix_error_proc_macros::{error_chain, proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+pub enum AuthError {
+    #[error("unauthorized")]
+    #[http_status(Unauthorized)]
+    Unauthorized
+}
+
+pub struct RateLimitError;
+
+impl From<RateLimitError> for HttpResponse {
+    fn from(_: RateLimitError) -> Self {
+        HttpResponse::TooManyRequests().body("slow down")
+    }
+}
+
+error_chain!(pub TestError: AuthError, RateLimitError);
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(AuthError::Unauthorized)?
+}
+
+#[proof_route(get("/"))]
+async fn test_limited_route() -> HttpResult<TestError> {
+    Err(RateLimitError)?
+}
+
+#[test]
+async fn should_respond_as_chained_error() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "unauthorized");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_chain_plain_responses() {
+    let (thread, server, address) = web_server!(test_limited_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::TOO_MANY_REQUESTS);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "slow down");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_name_variants_without_suffix() {
+    assert!(matches!(TestError::from(RateLimitError), TestError::RateLimit(_)));
+    assert!(matches!(TestError::from(AuthError::Unauthorized), TestError::Auth(AuthError::Unauthorized)));
+}
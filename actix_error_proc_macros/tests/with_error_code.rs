@@ -0,0 +1,107 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use serde_json::{from_str, json, Value};
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(json_body, code_field = "code")]
+enum TestError {
+    #[error("banned")]
+    #[http_status(Forbidden)]
+    #[error_code(1042)]
+    Banned,
+
+    #[error("internal")]
+    Internal,
+
+    #[error("invalid")]
+    #[http_status(BadRequest)]
+    #[http_json_body(key = "message")]
+    #[error_code(7)]
+    Invalid
+}
+
+#[proof_route(get("/"))]
+async fn banned_route() -> HttpResult<TestError> {
+    Err(TestError::Banned)
+}
+
+#[proof_route(get("/"))]
+async fn internal_route() -> HttpResult<TestError> {
+    Err(TestError::Internal)
+}
+
+#[proof_route(get("/"))]
+async fn invalid_route() -> HttpResult<TestError> {
+    Err(TestError::Invalid)
+}
+
+#[test]
+async fn should_include_error_code() {
+    let (thread, server, address) = web_server!(banned_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::FORBIDDEN);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(from_str::<Value>(&text).unwrap(), json!({"code": 1042, "error": "banned"}));
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_omit_missing_error_code() {
+    let (thread, server, address) = web_server!(internal_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    let body = from_str::<Value>(&text).unwrap();
+
+    assert_eq!(body, json!({"error": "internal"}));
+    assert!(body.get("code").is_none());
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_include_error_code_with_custom_key() {
+    let (thread, server, address) = web_server!(invalid_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(from_str::<Value>(&text).unwrap(), json!({"code": 7, "message": "invalid"}));
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,83 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Debug)]
+#[actix_error(expose_source = false)]
+enum TestError {
+    #[http_status(BadGateway)]
+    Hidden,
+
+    #[http_status(BadGateway)]
+    #[http_expose_source(true)]
+    Exposed
+}
+
+// the alternate format carries the details of the source.
+impl Display for TestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            write!(f, "upstream failed: connection refused")
+        } else {
+            write!(f, "upstream failed")
+        }
+    }
+}
+
+impl std::error::Error for TestError {}
+
+#[proof_route(get("/"))]
+async fn hidden_route() -> HttpResult<TestError> {
+    Err(TestError::Hidden)
+}
+
+#[proof_route(get("/"))]
+async fn exposed_route() -> HttpResult<TestError> {
+    Err(TestError::Exposed)
+}
+
+#[test]
+async fn should_hide_source() {
+    let (thread, server, address) = web_server!(hidden_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_GATEWAY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "upstream failed");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_expose_variant_source() {
+    let (thread, server, address) = web_server!(exposed_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_GATEWAY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "upstream failed: connection refused");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,77 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{web::Form, HttpResponse};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use tokio::test;
+use thiserror::Error;
+use crate::shared::HttpResult;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+pub enum TestError {
+    #[http_status(UnprocessableEntity)]
+    #[error("bad_form")]
+    BadForm
+}
+
+#[derive(Deserialize)]
+pub struct Login {
+    name: String,
+    age: u32
+}
+
+#[proof_route(post("/"))]
+async fn test_route(#[or(TestError::BadForm)] login: Form<Login>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(format!("{} {}", login.name, login.age)))
+}
+
+#[test]
+async fn should_override_invalid_form() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body("name=test&age=old")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "bad_form");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_extract_form() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .header("Content-Type", "application/x-www-form-urlencoded")
+        .body("name=test&age=18")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "test 18");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,37 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+/// Documents the route, the docs are set on the registered service.
+#[proof_route(get("/"))]
+#[allow(unused_variables)]
+async fn test_route() -> HttpResult<TestError> {
+    let unused = 0; // the lint level applies to the body.
+    Ok(HttpResponse::Ok().body("ok"))
+}
+
+#[test]
+async fn should_forward_attributes() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,80 @@
+use std::fmt::Display;
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{web::Json, HttpResponse};
+use crate::shared::HttpResult;
+use reqwest::{Client, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("invalid body")]
+    #[http_status(BadRequest)]
+    InvalidBody
+}
+
+#[derive(Deserialize)]
+struct Item {
+    name: String
+}
+
+impl Display for Item {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "item {}", self.name)
+    }
+}
+
+#[proof_route(post("/"), put("/"))]
+async fn test_route<T>(#[or(TestError::InvalidBody)] body: Json<T>) -> HttpResult<TestError>
+where
+    T: DeserializeOwned + Display
+{
+    Ok(HttpResponse::Ok().body(body.into_inner().to_string()))
+}
+
+#[test]
+async fn should_serve_generic_handler() {
+    let (thread, server, address) = web_server!(test_route::<Item>());
+
+    for request in [Client::new().post(&address), Client::new().put(&address)] {
+        let result = request
+            .header("Content-Type", "application/json")
+            .body(r#"{"name":"test"}"#)
+            .send()
+            .await
+            .expect("Error while making the request.");
+
+        assert_eq!(result.status(), StatusCode::OK);
+
+        let text = result
+            .text()
+            .await
+            .expect("Error while reading response body.");
+
+        assert_eq!(text, "item test");
+    }
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_respond_with_error_of_generic_handler() {
+    let (thread, server, address) = web_server!(test_route::<Item>());
+
+    let result = Client::new()
+        .post(address)
+        .header("Content-Type", "application/json")
+        .body("{}")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,109 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use core::fmt::{Debug, Display};
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(impl_into_string, retriable)]
+enum TestError<T: Display + Debug> {
+    #[error("test {0}")]
+    #[http_status(BadRequest)]
+    Test(T)
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestBorrowedError<'a> {
+    #[error("test2 {0}")]
+    #[http_status(NotFound)]
+    Test2(&'a str)
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestBoundedError<T>
+where
+    T: Display + Debug
+{
+    #[error("test3 {0}")]
+    #[http_status(Conflict)]
+    Test3(T)
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(impl_into_string, retriable, with_status_fn, with_is_client_error, impl_try_from_status, impl_response_error)]
+enum TestBoundedFlagsError<T>
+where
+    T: Display + Debug + Default
+{
+    #[error("test4 {0}")]
+    #[http_status(UnprocessableEntity)]
+    Test4(T),
+    #[error("gone")]
+    #[http_status(Gone)]
+    Gone
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError<u32>> {
+    Err(TestError::Test(1))
+}
+
+#[test]
+async fn should_convert_generic_enum() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "test 1");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_implement_generic_methods() {
+    assert!(TestError::Test("a").is_retriable());
+    assert_eq!(String::from(TestError::Test("a")), "test a");
+}
+
+#[test]
+async fn should_convert_enum_with_lifetime() {
+    let message = String::from("borrowed");
+    let response: HttpResponse = TestBorrowedError::Test2(&message).into();
+
+    assert_eq!(response.status().as_u16(), 404);
+}
+
+#[test]
+async fn should_convert_enum_with_where_clause() {
+    let response: HttpResponse = TestBoundedError::Test3(3.5).into();
+    let error: actix_web::Error = TestBoundedError::Test3(3.5).into();
+
+    assert_eq!(response.status().as_u16(), 409);
+    assert_eq!(error.as_response_error().status_code().as_u16(), 409);
+}
+
+#[test]
+async fn should_implement_flags_with_where_clause() {
+    let error = TestBoundedFlagsError::Test4(String::from("bounded"));
+
+    assert!(error.is_retriable());
+    assert!(error.is_client_error());
+    assert_eq!(error.status().as_u16(), 422);
+    assert_eq!(actix_web::ResponseError::status_code(&error).as_u16(), 422);
+    assert_eq!(String::from(error), "test4 bounded");
+    assert!(matches!(TestBoundedFlagsError::<u8>::try_from(410), Ok(TestBoundedFlagsError::Gone)));
+}
@@ -0,0 +1,71 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use thiserror::Error;
+use crate::shared::HttpResult;
+use reqwest::{Client, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(NotFound)]
+    Test
+}
+
+#[proof_route(head("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body("ignored"))
+}
+
+#[proof_route(head("/"))]
+async fn test2_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[test]
+async fn should_respond_to_head() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .head(address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_return_head_error_status() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let result = Client::new()
+        .head(address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
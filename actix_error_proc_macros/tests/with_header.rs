@@ -0,0 +1,100 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::error::ParseError;
+use actix_web::http::header::{from_one_raw_str, Header as TypedHeader, HeaderName, HeaderValue, InvalidHeaderValue, TryIntoHeaderValue};
+use actix_web::web::Header;
+use actix_web::{HttpMessage, HttpResponse};
+use crate::shared::HttpResult;
+use reqwest::{Client, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+struct ApiVersion(u32);
+
+impl TryIntoHeaderValue for ApiVersion {
+    type Error = InvalidHeaderValue;
+
+    fn try_into_value(self) -> Result<HeaderValue, Self::Error> {
+        HeaderValue::from_str(&self.0.to_string())
+    }
+}
+
+impl TypedHeader for ApiVersion {
+    fn name() -> HeaderName {
+        HeaderName::from_static("api-version")
+    }
+
+    fn parse<M: HttpMessage>(msg: &M) -> Result<Self, ParseError> {
+        from_one_raw_str(msg.headers().get(Self::name())).map(ApiVersion)
+    }
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("The API version header is missing or invalid.")]
+    #[http_status(PreconditionFailed)]
+    MissingHeader
+}
+
+#[proof_route(get("/"))]
+async fn test_route(#[or(TestError::MissingHeader)] version: Header<ApiVersion>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(version.0.0.to_string()))
+}
+
+#[test]
+async fn should_extract_typed_header() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .get(address)
+        .header("api-version", "2")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "2");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_use_or_on_missing_header() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .get(address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::PRECONDITION_FAILED);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_use_or_on_malformed_header() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .get(address)
+        .header("api-version", "two")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::PRECONDITION_FAILED);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
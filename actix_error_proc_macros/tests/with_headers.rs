@@ -0,0 +1,82 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{HttpResponse, HttpResponseBuilder};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn transformer(mut req: HttpResponseBuilder, _: String) -> HttpResponse {
+    let mut response = req.finish();
+
+    let code = response
+        .headers()
+        .get("x-error-code")
+        .cloned()
+        .expect("Missing x-error-code header.");
+
+    response.headers_mut().insert("x-seen-code".try_into().unwrap(), code);
+    response
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(Forbidden)]
+    #[http_header("X-Error-Code", "E001")]
+    #[http_header("X-Api-Version", "2")]
+    Test,
+
+    #[error("test2")]
+    #[http_header("X-Error-Code", "E002")]
+    #[http_transformer = "transformer"]
+    Test2
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[proof_route(get("/"))]
+async fn test2_route() -> HttpResult<TestError> {
+    Err(TestError::Test2)
+}
+
+#[test]
+async fn should_insert_every_header() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::FORBIDDEN);
+    assert_eq!(result.headers().get("x-error-code").unwrap(), "E001");
+    assert_eq!(result.headers().get("x-api-version").unwrap(), "2");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "test");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_insert_headers_before_transformer() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.headers().get("x-seen-code").unwrap(), "E002");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
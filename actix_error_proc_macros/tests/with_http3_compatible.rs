@@ -0,0 +1,29 @@
+use actix_error_proc_macros::ActixError;
+use actix_web::{http::StatusCode, HttpResponse, HttpResponseBuilder};
+use thiserror::Error;
+
+fn transformer(mut res: HttpResponseBuilder, fmt: String) -> HttpResponse {
+    res
+        .insert_header(("Connection", "keep-alive"))
+        .insert_header(("Keep-Alive", "timeout=5"))
+        .insert_header(("Test", "test"))
+        .body(fmt)
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(transformer = "transformer", http3_compatible)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test
+}
+
+#[test]
+fn should_remove_connection_specific_headers() {
+    let response: HttpResponse = TestError::Test.into();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert!(response.headers().get("connection").is_none());
+    assert!(response.headers().get("keep-alive").is_none());
+    assert_eq!(response.headers().get("test").unwrap(), "test");
+}
@@ -0,0 +1,46 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::web::Path;
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+fn __proof_route_get_user(id: u32) -> String {
+    format!("user {id}")
+}
+
+#[proof_route(get("/users/{id}"))]
+async fn get_user(id: Path<u32>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(__proof_route_get_user(*id)))
+}
+
+#[test]
+async fn should_not_shadow_user_functions() {
+    let (thread, server, address) = web_server!(get_user);
+
+    let result = get(format!("{address}users/7"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "user 7");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,153 @@
+use actix_error_proc_macros::{proof_routes, ActixError};
+use actix_web::web::{Json, Path};
+use actix_web::{HttpRequest, HttpResponse};
+use crate::shared::HttpResult;
+use reqwest::{get, Client, StatusCode};
+use serde::{de::DeserializeOwned, Deserialize};
+use std::fmt::Display;
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("The user is not valid.")]
+    #[http_status(BadRequest)]
+    InvalidUser
+}
+
+#[derive(Deserialize)]
+struct User {
+    name: String
+}
+
+struct UserController;
+
+#[proof_routes]
+impl UserController {
+    const GREETING: &'static str = "hello";
+
+    fn greet(name: &str) -> String {
+        format!("{} {name}", Self::GREETING)
+    }
+
+    #[proof_route(get("/users/{name}"), route_path_const)]
+    async fn get(name: Path<String>) -> HttpResult<TestError> {
+        Ok(HttpResponse::Ok().body(Self::greet(&name)))
+    }
+
+    #[proof_route(post("/users"), inject_request = "req")]
+    async fn create(#[or(TestError::InvalidUser)] user: Json<User>) -> HttpResult<TestError> {
+        let _: &HttpRequest = &req;
+
+        Ok(HttpResponse::Created().body(Self::greet(&user.name)))
+    }
+
+    #[proof_route(post("/names"))]
+    async fn echo<T: DeserializeOwned + Display>(#[or(TestError::InvalidUser)] name: Json<T>) -> HttpResult<TestError> {
+        Ok(HttpResponse::Ok().body(Self::greet(&name.to_string())))
+    }
+
+    #[proof_route(post("/guests"))]
+    async fn guest(#[optional] user: Json<User>) -> HttpResult<TestError> {
+        let name = user.map_or_else(|| "guest".to_string(), |user| user.into_inner().name);
+
+        Ok(HttpResponse::Ok().body(Self::greet(&name)))
+    }
+}
+
+#[test]
+async fn should_serve_associated_route() {
+    let (thread, server, address) = web_server!(UserController_get);
+
+    let result = get(format!("{address}users/john"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "hello john");
+    assert_eq!(USER_CONTROLLER_GET_ROUTE_PATH, "/users/{name}");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_keep_extraction_attributes() {
+    let (thread, server, address) = web_server!(UserController_create);
+
+    let result = Client::new()
+        .post(format!("{address}users"))
+        .body("not json")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let result = Client::new()
+        .post(format!("{address}users"))
+        .header("content-type", "application/json")
+        .body(r#"{"name": "jane"}"#)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::CREATED);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_pass_optional_parameters() {
+    let (thread, server, address) = web_server!(UserController_guest);
+
+    let result = Client::new()
+        .post(format!("{address}guests"))
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "hello guest");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_serve_generic_associated_route() {
+    let (thread, server, address) = web_server!(UserController_echo::<String>());
+
+    let result = Client::new()
+        .post(format!("{address}names"))
+        .header("Content-Type", "application/json")
+        .body("\"john\"")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "hello john");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
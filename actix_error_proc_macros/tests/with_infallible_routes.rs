@@ -0,0 +1,58 @@
+use actix_error_proc_macros::proof_route;
+use actix_web::{HttpResponse, Responder};
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[proof_route(get("/health"))]
+async fn health_route() -> HttpResponse {
+    HttpResponse::Ok().body("healthy")
+}
+
+#[proof_route(get("/name"))]
+async fn responder_route() -> impl Responder {
+    "responder"
+}
+
+#[test]
+async fn should_return_response() {
+    let (thread, server, address) = web_server!(health_route);
+
+    let result = get(format!("{address}health"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "healthy");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_return_responder() {
+    let (thread, server, address) = web_server!(responder_route);
+
+    let result = get(format!("{address}name"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "responder");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,57 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use reqwest::{Client, StatusCode};
+use tokio::test;
+use thiserror::Error;
+use crate::shared::HttpResult;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+pub enum TestError {
+    #[http_status(Unauthorized)]
+    #[error("missing_token")]
+    MissingToken
+}
+
+#[proof_route(get("/"), inject_request = "req")]
+async fn test_route() -> HttpResult<TestError> {
+    let token = req
+        .headers()
+        .get("Authorization")
+        .ok_or(TestError::MissingToken)?;
+
+    Ok(HttpResponse::Ok().body(token.to_str().unwrap().to_string()))
+}
+
+#[test]
+async fn should_inject_request() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .get(&address)
+        .header("Authorization", "Bearer test")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "Bearer test");
+
+    let result = Client::new()
+        .get(&address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,79 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(impl_into_string)]
+enum TestError {
+    #[error("test {0}")]
+    #[http_status(BadRequest)]
+    Test(i32),
+
+    #[error("the database failed")]
+    #[http_status(InternalServerError, message = "Something went wrong.")]
+    Database,
+
+    #[error("the field {0} is missing")]
+    #[http_status(BadRequest, body_fn = "missing_field_body")]
+    MissingField(String)
+}
+
+fn missing_field_body(error: &TestError) -> String {
+    match error {
+        TestError::MissingField(field) => format!("{{\"missing\":\"{field}\"}}"),
+        _ => String::new()
+    }
+}
+
+#[derive(ActixError, Debug)]
+#[actix_error(impl_into_string, expose_source = false)]
+enum TestSourceError {
+    #[http_status(BadGateway)]
+    Hidden,
+
+    #[http_status(BadGateway)]
+    #[http_expose_source(true)]
+    Exposed
+}
+
+impl Display for TestSourceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if f.alternate() {
+            write!(f, "upstream failed: connection refused")
+        } else {
+            write!(f, "upstream failed")
+        }
+    }
+}
+
+impl std::error::Error for TestSourceError {}
+
+#[test]
+fn should_convert_into_string() {
+    let message: String = TestError::Test(1).into();
+
+    assert_eq!(message, "test 1");
+}
+
+#[test]
+fn should_use_static_message() {
+    let message: String = TestError::Database.into();
+
+    assert_eq!(message, "Something went wrong.");
+}
+
+#[test]
+fn should_use_body_fn() {
+    let message: String = TestError::MissingField("name".into()).into();
+
+    assert_eq!(message, "{\"missing\":\"name\"}");
+}
+
+#[test]
+fn should_follow_expose_source() {
+    let hidden: String = TestSourceError::Hidden.into();
+    let exposed: String = TestSourceError::Exposed.into();
+
+    assert_eq!(hidden, "upstream failed");
+    assert_eq!(exposed, "upstream failed: connection refused");
+}
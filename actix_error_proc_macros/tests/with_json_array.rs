@@ -0,0 +1,84 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{web::Json, HttpResponse};
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+use tokio::test;
+use serde::Deserialize;
+use thiserror::Error;
+use crate::shared::HttpResult;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+pub enum TestError {
+    #[http_status(UnprocessableEntity)]
+    #[error("bad_array")]
+    BadArray
+}
+
+#[derive(Deserialize)]
+pub struct User {
+    name: String,
+    #[allow(unused)]
+    age: i32
+}
+
+#[proof_route(post("/"))]
+async fn test_route(#[or(TestError::BadArray)] users: Json<Vec<User>>) -> HttpResult<TestError> {
+    let names = users
+        .iter()
+        .map(|user| user.name.as_str())
+        .collect::<Vec<_>>();
+
+    Ok(HttpResponse::Ok().body(names.join(",")))
+}
+
+#[test]
+async fn should_extract_array() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .body(json!([{"name": "a", "age": 18}, {"name": "b", "age": 21}]).to_string())
+        .header("Content-Type", "application/json")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "a,b");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_override_malformed_array() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .body(json!({"name": "a", "age": 18}).to_string())
+        .header("Content-Type", "application/json")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "bad_array");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,114 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{HttpResponse, HttpResponseBuilder};
+use crate::shared::HttpResult;
+use serde_json::{from_str, json, Value};
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn transformer(mut req: HttpResponseBuilder, fmt: String) -> HttpResponse {
+    req
+        .append_header(("format", fmt))
+        .finish()
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(json_body)]
+enum TestError {
+    #[error("test \"quoted\"")]
+    #[http_status(BadRequest)]
+    Test
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(json_body, transformer = "transformer")]
+enum TestTransformedError {
+    #[error("test2")]
+    Test2
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(json_body, error_key_case = "SCREAMING_SNAKE_CASE")]
+enum TestRenamedError {
+    #[error("renamed")]
+    #[http_status(BadRequest)]
+    Renamed
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[proof_route(get("/"))]
+async fn test2_route() -> HttpResult<TestTransformedError> {
+    Err(TestTransformedError::Test2)
+}
+
+#[test]
+async fn should_return_json_body() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let header = result
+        .headers()
+        .get("content-type")
+        .expect("Missing content type header.");
+
+    assert_eq!(header, "application/json");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(from_str::<Value>(&text).unwrap(), json!({"error": "test \"quoted\""}));
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_pass_json_to_transformer() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let header = result
+        .headers()
+        .get("format")
+        .expect("Missing format header.");
+
+    assert_eq!(header, r#"{"error":"test2"}"#);
+
+    let header = result
+        .headers()
+        .get("content-type")
+        .expect("Missing content type header.");
+
+    assert_eq!(header, "application/json");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_rename_error_key() {
+    let response: HttpResponse = TestRenamedError::Renamed.into();
+    let body = actix_web::body::to_bytes(response.into_body())
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(from_str::<Value>(std::str::from_utf8(&body).unwrap()).unwrap(), json!({"ERROR": "renamed"}));
+}
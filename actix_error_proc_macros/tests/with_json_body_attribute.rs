@@ -0,0 +1,106 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use serde_json::{from_str, json, Value};
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn object_body(error: &TestError) -> Value {
+    json!({"code": 7, "description": error.to_string()})
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(json_body)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    #[http_json_body(key = "message")]
+    Test,
+
+    #[error("test2")]
+    #[http_json_body(object = "object_body")]
+    Test2,
+
+    #[error("test3")]
+    Test3
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestPlainError {
+    #[error("test4")]
+    #[http_json_body(key = "message")]
+    Test4
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[proof_route(get("/"))]
+async fn test2_route() -> HttpResult<TestError> {
+    Err(TestError::Test2)
+}
+
+#[proof_route(get("/"))]
+async fn test3_route() -> HttpResult<TestError> {
+    Err(TestError::Test3)
+}
+
+#[proof_route(get("/"))]
+async fn test4_route() -> HttpResult<TestPlainError> {
+    Err(TestPlainError::Test4)
+}
+
+macro_rules! json_response {
+    ($route:ident) => {{
+        let (thread, server, address) = web_server!($route);
+
+        let result = get(address)
+            .await
+            .expect("Error while making the request.");
+
+        let status = result.status();
+
+        let text = result
+            .text()
+            .await
+            .expect("Error while reading response body.");
+
+        server.stop(true).await;
+        thread.join().unwrap();
+
+        (status, from_str::<Value>(&text).expect("Expected a JSON body."))
+    }};
+}
+
+#[test]
+async fn should_use_variant_key() {
+    let (status, body) = json_response!(test_route);
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, json!({"message": "test"}));
+}
+
+#[test]
+async fn should_use_variant_object() {
+    let (_, body) = json_response!(test2_route);
+
+    assert_eq!(body, json!({"code": 7, "description": "test2"}));
+}
+
+#[test]
+async fn should_fall_back_to_enum_json_body() {
+    let (_, body) = json_response!(test3_route);
+
+    assert_eq!(body, json!({"error": "test3"}));
+}
+
+#[test]
+async fn should_not_require_enum_json_body() {
+    let (_, body) = json_response!(test4_route);
+
+    assert_eq!(body, json!({"message": "test4"}));
+}
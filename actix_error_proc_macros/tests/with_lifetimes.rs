@@ -0,0 +1,60 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum ParseError<'a> {
+    #[error("Unexpected token {0}")]
+    #[http_status(BadRequest)]
+    Unexpected(&'a str),
+
+    #[error("Unexpected end after {after}")]
+    #[http_status(UnprocessableEntity)]
+    UnexpectedEnd { after: &'a str }
+}
+
+#[derive(ActixError, Error, Debug)]
+#[error("Invalid field {0}")]
+#[http_status(BadRequest)]
+struct FieldError<'a>(&'a str);
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<ParseError<'static>> {
+    Err(ParseError::Unexpected("}"))
+}
+
+#[test]
+async fn should_convert_static_borrow() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "Unexpected token }");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_convert_local_borrow() {
+    let input = String::from("value");
+    let response: HttpResponse = ParseError::UnexpectedEnd { after: &input }.into();
+    let error: actix_web::Error = FieldError(&input).into();
+
+    assert_eq!(response.status().as_u16(), 422);
+    assert_eq!(error.to_string(), "Invalid field value");
+}
@@ -0,0 +1,66 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{redirect::Policy, Client, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(PermanentRedirect)]
+    #[http_location("/new-path")]
+    Test,
+
+    #[error("test2")]
+    #[http_status(302)]
+    #[http_location("https://example.com/")]
+    Test2
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[proof_route(get("/"))]
+async fn test2_route() -> HttpResult<TestError> {
+    Err(TestError::Test2)
+}
+
+macro_rules! location_response {
+    ($route:ident) => {{
+        let (thread, server, address) = web_server!($route);
+
+        let result = Client::builder()
+            .redirect(Policy::none())
+            .build()
+            .unwrap()
+            .get(address)
+            .send()
+            .await
+            .expect("Error while making the request.");
+
+        server.stop(true).await;
+        thread.join().unwrap();
+
+        result
+    }};
+}
+
+#[test]
+async fn should_insert_location() {
+    let result = location_response!(test_route);
+
+    assert_eq!(result.status(), StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(result.headers().get("location").unwrap(), "/new-path");
+}
+
+#[test]
+async fn should_insert_location_with_numeric_status() {
+    let result = location_response!(test2_route);
+
+    assert_eq!(result.status(), StatusCode::FOUND);
+    assert_eq!(result.headers().get("location").unwrap(), "https://example.com/");
+}
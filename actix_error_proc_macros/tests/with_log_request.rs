@@ -0,0 +1,75 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use thiserror::Error;
+use tokio::test;
+use tracing::subscriber::set_global_default;
+use tracing_subscriber::fmt;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[proof_route(get("/users"), log_request)]
+async fn test_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Created().finish())
+}
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+async fn should_log_request_and_response() {
+    let capture = Capture::default();
+    let writer = capture.clone();
+
+    // the server runs in its own thread, so the subscriber must be global.
+    set_global_default(
+        fmt()
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish()
+    )
+    .unwrap();
+
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(format!("{address}users"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::CREATED);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+
+    let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+
+    assert!(output.contains("HTTP request received"), "{output}");
+    assert!(output.contains("method=GET"), "{output}");
+    assert!(output.contains("path=/users"), "{output}");
+    assert!(output.contains("remote=Some(127.0.0.1:"), "{output}");
+    assert!(output.contains("HTTP response sent"), "{output}");
+    assert!(output.contains("status=201"), "{output}");
+    assert!(output.contains("elapsed="), "{output}");
+}
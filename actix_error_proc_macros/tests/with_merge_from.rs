@@ -0,0 +1,51 @@
+use actix_error_proc_macros::ActixError;
+use actix_web::{http::StatusCode, HttpResponse};
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum AuthError {
+    #[error("unauthorized")]
+    #[http_status(Unauthorized)]
+    Unauthorized
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(impl_into_string)]
+enum DatabaseError {
+    #[error("not found")]
+    NotFound
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(merge_from = "AuthError", merge_from = "DatabaseError")]
+enum AppError {
+    #[error("{0}")]
+    #[http_status(Unauthorized)]
+    Auth(AuthError),
+
+    #[error("internal: {message}")]
+    #[actix_error(catch_all)]
+    Internal { message: String }
+}
+
+#[test]
+fn should_move_into_wrapping_variant() {
+    let error = AppError::from(AuthError::Unauthorized);
+
+    assert!(matches!(error, AppError::Auth(AuthError::Unauthorized)));
+
+    let response: HttpResponse = error.into();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[test]
+fn should_convert_into_catch_all_variant() {
+    let error = AppError::from(DatabaseError::NotFound);
+
+    assert!(matches!(error, AppError::Internal { ref message } if message == "not found"));
+
+    let response: HttpResponse = error.into();
+
+    assert_eq!(response.status(), StatusCode::INTERNAL_SERVER_ERROR);
+}
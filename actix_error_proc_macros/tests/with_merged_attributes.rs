@@ -0,0 +1,51 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{HttpResponse, HttpResponseBuilder};
+use crate::shared::HttpResult;
+use serde_json::{from_str, json, Value};
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn transformer(mut req: HttpResponseBuilder, fmt: String) -> HttpResponse {
+    req
+        .append_header(("transformed", "true"))
+        .body(fmt)
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(transformer = "transformer")]
+#[actix_error(default_status = "BadRequest")]
+#[actix_error(json_body)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[test]
+async fn should_merge_attributes() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(result.headers().get("transformed").unwrap(), "true");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(from_str::<Value>(&text).unwrap(), json!({"error": "test"}));
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
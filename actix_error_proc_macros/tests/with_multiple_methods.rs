@@ -0,0 +1,56 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use thiserror::Error;
+use crate::shared::HttpResult;
+use reqwest::{get, Client, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test
+}
+
+#[proof_route(get("/"), head("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[test]
+async fn should_respond_to_every_method() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(&address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "test");
+
+    let result = Client::new()
+        .head(&address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let result = Client::new()
+        .post(&address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,44 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(with_status_fn)]
+#[non_exhaustive]
+enum TestError {
+    #[error("The user was not found.")]
+    #[http_status(NotFound)]
+    NotFound
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::NotFound)
+}
+
+#[test]
+async fn should_derive_for_non_exhaustive_enums() {
+    assert_eq!(TestError::NotFound.status(), actix_web::http::StatusCode::NOT_FOUND);
+
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "The user was not found.");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
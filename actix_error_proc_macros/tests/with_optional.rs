@@ -0,0 +1,103 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{web::Json, HttpResponse};
+use reqwest::{Client, StatusCode};
+use serde::Deserialize;
+use serde_json::json;
+use tokio::test;
+use thiserror::Error;
+use crate::shared::HttpResult;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+pub enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[derive(Deserialize)]
+pub struct User {
+    name: String
+}
+
+#[proof_route(post("/"))]
+async fn test_route(#[optional] user: Json<User>) -> HttpResult<TestError> {
+    Ok(match user {
+        Some(user) => HttpResponse::Ok().body(user.name.clone()),
+        None => HttpResponse::Ok().body("anonymous"),
+    })
+}
+
+#[test]
+async fn should_extract_present_value() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .header("Content-Type", "application/json")
+        .body(json!({"name": "test"}).to_string())
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "test");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_extract_none_when_absent() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "anonymous");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_extract_none_when_invalid() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .header("Content-Type", "application/json")
+        .body("invalid json")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "anonymous");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
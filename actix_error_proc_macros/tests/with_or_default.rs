@@ -0,0 +1,140 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::web::{Path, Query};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("not found")]
+    #[http_status(NotFound)]
+    NotFound,
+    #[error("bad request {0}")]
+    #[http_status(BadRequest)]
+    WithField(u32),
+    #[error("bad request")]
+    #[http_status(BadRequest)]
+    BadRequest,
+    #[error("second bad request")]
+    #[http_status(400)]
+    SecondBadRequest
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(default_or_variant = "Malformed")]
+#[allow(unused)]
+enum TestNamedError {
+    #[error("bad request")]
+    #[http_status(BadRequest)]
+    BadRequest,
+    #[error("malformed")]
+    #[http_status(UnprocessableEntity)]
+    Malformed
+}
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestPriorityError {
+    #[error("bad request")]
+    #[http_status(BadRequest)]
+    BadRequest,
+    #[error("prioritized bad request")]
+    #[http_status(BadRequest)]
+    #[actix_error(priority = 10)]
+    PrioritizedBadRequest
+}
+
+#[derive(Deserialize)]
+struct Page {
+    page: u32
+}
+
+#[proof_route(get("/users/{id}"))]
+async fn test_route(#[or_default] id: Path<u32>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(id.to_string()))
+}
+
+#[proof_route(get("/users/{id}"))]
+async fn test_priority_route(#[or_default] id: Path<u32>) -> HttpResult<TestPriorityError> {
+    Ok(HttpResponse::Ok().body(id.to_string()))
+}
+
+#[proof_route(get("/"))]
+async fn test_named_route(#[or_default] query: Query<Page>) -> HttpResult<TestNamedError> {
+    Ok(HttpResponse::Ok().body(query.page.to_string()))
+}
+
+#[test]
+async fn should_use_first_bad_request() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(format!("{address}users/abc"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "bad request");
+
+    let result = get(format!("{address}users/1"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_use_named_default() {
+    let (thread, server, address) = web_server!(test_named_route);
+
+    let result = get(format!("{address}?page=abc"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "malformed");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_use_prioritized_bad_request() {
+    let (thread, server, address) = web_server!(test_priority_route);
+
+    let result = get(format!("{address}users/abc"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "prioritized bad request");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
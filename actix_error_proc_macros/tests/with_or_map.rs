@@ -0,0 +1,110 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::web::{Data, Json, Query};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use reqwest::{get, Client, StatusCode};
+use serde::Deserialize;
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("invalid: {0}")]
+    #[http_status(UnprocessableEntity)]
+    Invalid(u16),
+    #[error("missing data")]
+    #[http_status(ServiceUnavailable)]
+    MissingData
+}
+
+#[derive(Deserialize)]
+struct Page {
+    page: u32
+}
+
+fn invalid(error: actix_web::Error) -> TestError {
+    TestError::Invalid(error.as_response_error().status_code().as_u16())
+}
+
+#[proof_route(post("/"))]
+async fn test_route(#[or_map(|err| TestError::Invalid(err.as_response_error().status_code().as_u16()))] body: Json<Vec<u32>>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(body.len().to_string()))
+}
+
+#[proof_route(get("/"))]
+async fn test_fn_route(#[or_map(invalid)] query: Query<Page>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(query.page.to_string()))
+}
+
+#[proof_route(get("/"))]
+async fn test_data_route(#[or_map(|_| TestError::MissingData)] data: Data<u32>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(data.to_string()))
+}
+
+#[test]
+async fn should_map_extraction_error() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .body("invalid json")
+        .header("Content-Type", "application/json")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "invalid: 400");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_map_with_function() {
+    let (thread, server, address) = web_server!(test_fn_route);
+
+    let result = get(format!("{address}?page=abc"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let result = get(format!("{address}?page=3"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "3");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_map_missing_data() {
+    let (thread, server, address) = web_server!(test_data_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
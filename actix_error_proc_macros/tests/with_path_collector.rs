@@ -0,0 +1,96 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{web::Path, HttpResponse};
+use reqwest::{get, StatusCode};
+use tokio::test;
+use thiserror::Error;
+use crate::shared::HttpResult;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+pub enum TestError {
+    #[http_status(UnprocessableEntity)]
+    #[error("bad_id")]
+    BadId
+}
+
+#[proof_route(get("/users/{id}"))]
+async fn test_route(#[or(TestError::BadId)] id: Path<u32>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(id.to_string()))
+}
+
+#[proof_route(get("/users/{id}/posts/{slug}"))]
+async fn test_segments_route(#[or(TestError::BadId)] path: Path<(u32, String)>) -> HttpResult<TestError> {
+    let (id, slug) = path.into_inner();
+
+    Ok(HttpResponse::Ok().body(format!("{id} {slug}")))
+}
+
+#[test]
+async fn should_override_malformed_path() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(format!("{address}users/abc"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "bad_id");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_extract_path() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(format!("{address}users/42"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "42");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_extract_many_segments() {
+    let (thread, server, address) = web_server!(test_segments_route);
+
+    let result = get(format!("{address}users/7/posts/hello"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "7 hello");
+
+    let result = get(format!("{address}users/seven/posts/hello"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
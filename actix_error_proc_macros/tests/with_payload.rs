@@ -0,0 +1,89 @@
+use std::future::{ready, Ready};
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::web;
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use reqwest::{Client, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("The body couldn't be read.")]
+    #[http_status(BadRequest)]
+    InvalidBody
+}
+
+#[proof_route(post("/"))]
+async fn test_route(body: web::Payload) -> HttpResult<TestError> {
+    let body = body
+        .to_bytes()
+        .await
+        .map_err(|_| TestError::InvalidBody)?;
+
+    Ok(HttpResponse::Ok().body(body.len().to_string()))
+}
+
+// an extractor of the same name that can fail.
+mod auth {
+    use super::*;
+    use actix_web::{dev, error::ErrorUnauthorized, FromRequest, HttpRequest};
+
+    pub struct Payload;
+
+    impl FromRequest for Payload {
+        type Error = actix_web::Error;
+        type Future = Ready<Result<Self, Self::Error>>;
+
+        fn from_request(_: &HttpRequest, _: &mut dev::Payload) -> Self::Future {
+            ready(Err(ErrorUnauthorized("unauthorized")))
+        }
+    }
+}
+
+#[proof_route(post("/"))]
+async fn auth_route(_token: auth::Payload) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[test]
+async fn should_read_payload_without_or() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .post(address)
+        .body("hello")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "5");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_respond_with_error_of_other_payload() {
+    let (thread, server, address) = web_server!(auth_route);
+
+    let result = Client::new()
+        .post(address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,105 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{HttpRequest, HttpResponse};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{Client, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("first")]
+    #[http_status(Unauthorized)]
+    First,
+
+    #[error("second")]
+    #[http_status(Forbidden)]
+    Second
+}
+
+fn first_hook(req: &HttpRequest) -> Result<(), TestError> {
+    match req.headers().contains_key("first") {
+        true => Ok(()),
+        false => Err(TestError::First)
+    }
+}
+
+fn second_hook(req: &HttpRequest) -> Result<(), TestError> {
+    match req.headers().contains_key("second") {
+        true => Ok(()),
+        false => Err(TestError::Second)
+    }
+}
+
+#[proof_route(get("/"), pre_hook = "first_hook")]
+async fn test_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route(get("/"), pre_hooks = ["first_hook", "second_hook"])]
+async fn test2_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[test]
+async fn should_return_hook_error() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = Client::new()
+        .get(&address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "first");
+
+    let result = Client::new()
+        .get(address)
+        .header("first", "")
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_run_hooks_in_order() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let cases = [
+        (vec![], StatusCode::UNAUTHORIZED),
+        (vec!["second"], StatusCode::UNAUTHORIZED),
+        (vec!["first"], StatusCode::FORBIDDEN),
+        (vec!["first", "second"], StatusCode::OK),
+    ];
+
+    for (headers, status) in cases {
+        let mut request = Client::new().get(&address);
+
+        for header in headers {
+            request = request.header(header, "");
+        }
+
+        let result = request
+            .send()
+            .await
+            .expect("Error while making the request.");
+
+        assert_eq!(result.status(), status);
+    }
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
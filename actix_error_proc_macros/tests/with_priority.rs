@@ -0,0 +1,52 @@
+use actix_error_proc_macros::ActixError;
+use actix_web::{http::StatusCode, HttpResponse};
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test,
+
+    #[error("test2")]
+    #[http_status(Unauthorized)]
+    #[actix_error(priority = 100)]
+    Test2,
+
+    #[error("test3")]
+    #[actix_error(priority = -1)]
+    Test3
+}
+
+#[derive(ActixError, Error, Debug, PartialEq)]
+#[actix_error(impl_try_from_status)]
+enum TestSharedError {
+    #[error("missing")]
+    #[http_status(BadRequest)]
+    Missing,
+
+    #[error("malformed")]
+    #[http_status(BadRequest)]
+    #[actix_error(priority = 10)]
+    Malformed
+}
+
+#[test]
+fn should_map_every_prioritized_variant() {
+    let cases = [
+        (TestError::Test, StatusCode::BAD_REQUEST),
+        (TestError::Test2, StatusCode::UNAUTHORIZED),
+        (TestError::Test3, StatusCode::INTERNAL_SERVER_ERROR),
+    ];
+
+    for (error, status) in cases {
+        let response: HttpResponse = error.into();
+
+        assert_eq!(response.status(), status);
+    }
+}
+
+#[test]
+fn should_pick_prioritized_variant_for_shared_status() {
+    assert_eq!(TestSharedError::try_from(400), Ok(TestSharedError::Malformed));
+}
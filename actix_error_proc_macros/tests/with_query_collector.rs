@@ -0,0 +1,90 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{web::Query, HttpResponse};
+use reqwest::{get, StatusCode};
+use serde::Deserialize;
+use tokio::test;
+use thiserror::Error;
+use crate::shared::HttpResult;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+pub enum TestError {
+    #[http_status(UnprocessableEntity)]
+    #[error("bad_query")]
+    BadQuery
+}
+
+#[derive(Deserialize)]
+pub struct Pagination {
+    page: u32
+}
+
+#[proof_route(get("/"))]
+async fn test_route(#[or(TestError::BadQuery)] pagination: Query<Pagination>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(pagination.page.to_string()))
+}
+
+// the `Query` extractor case.
+#[test]
+async fn should_override_missing_query() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "bad_query");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_override_malformed_query() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(format!("{address}?page=first"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "bad_query");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_extract_query() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(format!("{address}?page=3"))
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "3");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,67 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::dev::Service;
+use actix_web::web::{scope, ReqData};
+use actix_web::{HttpMessage, HttpResponse};
+use crate::shared::HttpResult;
+use reqwest::{get, StatusCode};
+use thiserror::Error;
+use tokio::test;
+
+mod shared;
+
+#[derive(Clone)]
+struct UserId(u32);
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("unreachable")]
+    Unreachable
+}
+
+#[proof_route(get("/"))]
+async fn test_route(user: ReqData<UserId>) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body(user.0.to_string()))
+}
+
+#[test]
+async fn should_extract_middleware_data() {
+    let (thread, server, address) = web_server!(
+        scope("")
+            .wrap_fn(|req, srv| {
+                req.extensions_mut().insert(UserId(5));
+                srv.call(req)
+            })
+            .service(test_route)
+    );
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "5");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_fail_without_middleware_data() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,97 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{HttpResponse, HttpResponseBuilder, ResponseError};
+use actix_web::http::StatusCode as ActixStatusCode;
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn transformer(mut req: HttpResponseBuilder, fmt: String) -> HttpResponse {
+    req.body(format!("transformed {fmt}"))
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(impl_response_error, transformer = "transformer")]
+enum TestError {
+    #[error("test")]
+    #[http_status(BadRequest)]
+    Test,
+
+    #[error("test2")]
+    #[http_status(451)]
+    Test2
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(impl_response_error)]
+enum TestWrappingError {
+    #[error(transparent)]
+    #[actix_transparent]
+    Inner(TestError),
+
+    #[error(transparent)]
+    #[actix_error(delegate_to = "TestError")]
+    Delegated(TestError)
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[test]
+async fn should_map_status_code() {
+    assert_eq!(TestError::Test.status_code(), ActixStatusCode::BAD_REQUEST);
+    assert_eq!(TestError::Test2.status_code(), ActixStatusCode::UNAVAILABLE_FOR_LEGAL_REASONS);
+}
+
+#[test]
+async fn should_delegate_to_inner_response_error() {
+    let error = TestWrappingError::Inner(TestError::Test2);
+
+    assert_eq!(error.status_code(), ActixStatusCode::UNAVAILABLE_FOR_LEGAL_REASONS);
+    assert_eq!(TestWrappingError::Delegated(TestError::Test).status_code(), ActixStatusCode::BAD_REQUEST);
+
+    let body = actix_web::body::to_bytes(error.error_response().into_body())
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(body, "transformed test2");
+}
+
+#[test]
+async fn should_use_transformer_in_error_response() {
+    let error: actix_web::Error = TestError::Test2.into();
+    let response = error.error_response();
+
+    assert_eq!(response.status(), ActixStatusCode::UNAVAILABLE_FOR_LEGAL_REASONS);
+
+    let body = actix_web::body::to_bytes(response.into_body())
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(body, "transformed test2");
+}
+
+#[test]
+async fn should_keep_route_response() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "transformed test");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
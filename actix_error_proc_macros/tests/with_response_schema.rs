@@ -0,0 +1,27 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use std::any::TypeId;
+use thiserror::Error;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[allow(unused)]
+struct User;
+
+#[proof_route(get("/"), response_schema = "Vec<User>")]
+async fn test_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[test]
+fn should_expose_response_schema() {
+    assert_eq!(test_route::RESPONSE_SCHEMA, TypeId::of::<Vec<User>>());
+}
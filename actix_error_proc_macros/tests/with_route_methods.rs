@@ -0,0 +1,60 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use thiserror::Error;
+use crate::shared::HttpResult;
+use reqwest::{Client, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[proof_route(route("/health", methods = "GET, HEAD"))]
+async fn test_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().body("healthy"))
+}
+
+#[test]
+async fn should_respond_to_listed_methods() {
+    let (thread, server, address) = web_server!(test_route);
+    let address = format!("{address}health");
+
+    let result = Client::new()
+        .get(&address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "healthy");
+
+    let result = Client::new()
+        .head(&address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    let result = Client::new()
+        .post(&address)
+        .send()
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,37 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use thiserror::Error;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[allow(unused)]
+enum TestError {
+    #[error("test")]
+    Test
+}
+
+#[proof_route(get("/users/{id}"), route_path_const)]
+async fn get_user() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+mod routes {
+    use super::*;
+
+    #[proof_route(post("/users"), const_vis = "pub(crate)")]
+    async fn create_user() -> HttpResult<TestError> {
+        Ok(HttpResponse::Ok().finish())
+    }
+}
+
+#[test]
+fn should_expose_route_path() {
+    assert_eq!(GET_USER_ROUTE_PATH, "/users/{id}");
+}
+
+#[test]
+fn should_use_const_visibility() {
+    assert_eq!(routes::CREATE_USER_ROUTE_PATH, "/users");
+}
@@ -0,0 +1,44 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(secure_display)]
+enum TestError {
+    #[error("operation failed: internal details")]
+    Test
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[test]
+async fn should_redact_details_in_release() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    if cfg!(debug_assertions) {
+        assert_eq!(text, "operation failed: internal details");
+    } else {
+        assert_eq!(text, "operation failed");
+    }
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,50 @@
+use actix_error_proc_macros::ActixError;
+use actix_web::{HttpResponse, ResponseError};
+use sentry::test::with_captured_events;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("The database failed.")]
+    #[capture_to_sentry]
+    Database,
+    #[error("The user was not found.")]
+    #[http_status(NotFound)]
+    NotFound
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(impl_response_error)]
+enum TestResponseError {
+    #[error("The cache failed.")]
+    #[capture_to_sentry]
+    Cache
+}
+
+#[test]
+fn should_capture_marked_variants() {
+    let events = with_captured_events(|| {
+        let _: HttpResponse = TestError::Database.into();
+    });
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].exception[0].value.as_deref(), Some("The database failed."));
+}
+
+#[test]
+fn should_not_capture_other_variants() {
+    let events = with_captured_events(|| {
+        let _: HttpResponse = TestError::NotFound.into();
+    });
+
+    assert!(events.is_empty());
+}
+
+#[test]
+fn should_capture_response_errors() {
+    let events = with_captured_events(|| {
+        TestResponseError::Cache.error_response();
+    });
+
+    assert_eq!(events.len(), 1);
+}
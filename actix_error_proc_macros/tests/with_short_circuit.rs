@@ -0,0 +1,63 @@
+use std::future::{ready, Ready};
+use std::sync::atomic::{AtomicBool, Ordering};
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{dev::Payload, error::ErrorBadRequest, FromRequest, HttpRequest, HttpResponse};
+use reqwest::{get, StatusCode};
+use tokio::test;
+use thiserror::Error;
+use crate::shared::HttpResult;
+
+mod shared;
+
+static EXTRACTED: AtomicBool = AtomicBool::new(false);
+
+#[derive(ActixError, Error, Debug)]
+pub enum TestError {
+    #[http_status(ImATeapot)]
+    #[error("failed")]
+    Failed
+}
+
+pub struct Failing;
+
+impl FromRequest for Failing {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(_: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(Err(ErrorBadRequest("failing")))
+    }
+}
+
+pub struct Recorded;
+
+impl FromRequest for Recorded {
+    type Error = actix_web::Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(_: &HttpRequest, _: &mut Payload) -> Self::Future {
+        EXTRACTED.store(true, Ordering::SeqCst);
+        ready(Ok(Recorded))
+    }
+}
+
+#[proof_route(get("/"))]
+#[allow(unused_variables)]
+async fn test_route(#[or(TestError::Failed)] failing: Failing, recorded: Recorded) -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[test]
+async fn should_skip_extractors_after_failure() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::IM_A_TEAPOT);
+    assert!(!EXTRACTED.load(Ordering::SeqCst));
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
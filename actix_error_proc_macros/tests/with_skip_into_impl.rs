@@ -0,0 +1,48 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(skip_into_impl)]
+enum TestError {
+    #[error("custom conversion")]
+    Test
+}
+
+impl From<TestError> for HttpResponse {
+    fn from(error: TestError) -> Self {
+        HttpResponse::ImATeapot()
+            .body(error.to_string())
+    }
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Test)
+}
+
+#[test]
+async fn should_use_custom_conversion() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::IM_A_TEAPOT);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "custom conversion");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,79 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("The query {0} failed.")]
+    #[http_status(InternalServerError, message = "Something went wrong.")]
+    Database(String)
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(json_body)]
+enum JsonError {
+    #[error("The token {0} expired.")]
+    #[http_status(Unauthorized, message = "Unauthorized.")]
+    Expired(String)
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Database("SELECT secret".into()))
+}
+
+#[proof_route(get("/"))]
+async fn json_route() -> HttpResult<JsonError> {
+    Err(JsonError::Expired("abc".into()))
+}
+
+#[test]
+async fn should_send_static_message() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "Something went wrong.");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_wrap_static_message_in_json() {
+    let (thread, server, address) = web_server!(json_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, r#"{"error":"Unauthorized."}"#);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_keep_display_message() {
+    assert_eq!(TestError::Database("q".into()).to_string(), "The query q failed.");
+}
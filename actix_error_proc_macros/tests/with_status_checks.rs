@@ -0,0 +1,54 @@
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(with_is_client_error, with_is_server_error)]
+enum TestError {
+    #[error("bad request")]
+    #[http_status(BadRequest)]
+    BadRequest,
+
+    #[error("unauthorized")]
+    #[http_status(Unauthorized)]
+    Unauthorized,
+
+    #[error("internal")]
+    Internal,
+
+    #[error("unavailable")]
+    #[http_status(ServiceUnavailable)]
+    Unavailable
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(with_status_fn, with_is_client_error)]
+enum StatusError {
+    #[error("not found")]
+    #[http_status(NotFound)]
+    NotFound,
+
+    #[error("internal")]
+    Internal
+}
+
+#[test]
+fn should_bucket_client_errors() {
+    assert!(TestError::BadRequest.is_client_error());
+    assert!(TestError::Unauthorized.is_client_error());
+    assert!(!TestError::Internal.is_client_error());
+    assert!(!TestError::Unavailable.is_client_error());
+}
+
+#[test]
+fn should_bucket_server_errors() {
+    assert!(!TestError::BadRequest.is_server_error());
+    assert!(!TestError::Unauthorized.is_server_error());
+    assert!(TestError::Internal.is_server_error());
+    assert!(TestError::Unavailable.is_server_error());
+}
+
+#[test]
+fn should_check_with_status_fn() {
+    assert!(StatusError::NotFound.is_client_error());
+    assert!(!StatusError::Internal.is_client_error());
+}
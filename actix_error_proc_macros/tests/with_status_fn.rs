@@ -0,0 +1,70 @@
+use actix_error_proc_macros::ActixError;
+use actix_web::http::StatusCode;
+use actix_web::HttpResponse;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(with_status_fn)]
+enum AuthError {
+    #[error("unauthorized")]
+    #[http_status(Unauthorized)]
+    Unauthorized
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(with_status_fn, default_status = "BadRequest")]
+enum TestError {
+    #[error("not found")]
+    #[http_status(NotFound)]
+    NotFound,
+
+    #[error("invalid")]
+    Invalid,
+
+    #[error(transparent)]
+    #[actix_transparent]
+    Auth(AuthError)
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(with_status_fn)]
+enum TestNamedError {
+    #[error("teapot")]
+    #[http_status(ImATeapot)]
+    Teapot,
+
+    #[error("version")]
+    #[http_status(VersionNotSupported)]
+    Version,
+
+    #[error("uri")]
+    #[http_status(UriTooLong)]
+    Uri,
+
+    #[error("network")]
+    #[http_status(NetworkAuthenticationRequired)]
+    Network
+}
+
+#[test]
+fn should_match_builder_statuses() {
+    assert_eq!(TestNamedError::Teapot.status(), HttpResponse::ImATeapot().finish().status());
+    assert_eq!(TestNamedError::Version.status(), HttpResponse::VersionNotSupported().finish().status());
+    assert_eq!(TestNamedError::Uri.status(), HttpResponse::UriTooLong().finish().status());
+    assert_eq!(TestNamedError::Network.status(), HttpResponse::NetworkAuthenticationRequired().finish().status());
+}
+
+#[test]
+fn should_return_variant_status() {
+    assert_eq!(TestError::NotFound.status(), StatusCode::NOT_FOUND);
+}
+
+#[test]
+fn should_return_default_status() {
+    assert_eq!(TestError::Invalid.status(), StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn should_return_inner_status() {
+    assert_eq!(TestError::Auth(AuthError::Unauthorized).status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,114 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{HttpResponse, HttpResponseBuilder};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn transformer(mut res: HttpResponseBuilder, fmt: String) -> HttpResponse {
+    res
+        .append_header(("format", fmt))
+        .body("no")
+}
+
+#[derive(ActixError, Error, Debug)]
+#[error("unit")]
+#[http_status(NotFound)]
+struct UnitError;
+
+#[derive(ActixError, Error, Debug)]
+#[error("tuple {0}")]
+#[http_status(Conflict)]
+struct TupleError(i32);
+
+#[derive(ActixError, Error, Debug)]
+#[error("the field {field} is invalid: {message}")]
+#[http_status(BadRequest)]
+#[actix_error(transformer = "transformer")]
+struct ValidationError {
+    field: String,
+    message: String
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<ValidationError> {
+    Err(ValidationError {
+        field: "name".into(),
+        message: "too long".into()
+    })
+}
+
+#[proof_route(get("/"))]
+async fn test2_route() -> HttpResult<UnitError> {
+    Err(UnitError)
+}
+
+#[proof_route(get("/"))]
+async fn test3_route() -> HttpResult<TupleError> {
+    Err(TupleError(1))
+}
+
+#[test]
+async fn should_convert_unit_struct() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "unit");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_convert_tuple_struct() {
+    let (thread, server, address) = web_server!(test3_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::CONFLICT);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "tuple 1");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_transform_named_struct() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let header = result
+        .headers()
+        .get("format")
+        .expect("Missing format header.");
+
+    assert_eq!(header, "the field name is invalid: too long");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,89 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::{rt::time::sleep, HttpResponse};
+use crate::shared::HttpResult;
+use std::time::Duration;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error("timed out")]
+    #[http_status(RequestTimeout)]
+    Timeout
+}
+
+#[proof_route(get("/"), timeout_ms = 100, timeout_error = "TestError::Timeout")]
+async fn test_route() -> HttpResult<TestError> {
+    sleep(Duration::from_millis(200)).await;
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route(get("/"), timeout_ms = 200, timeout_error = "TestError::Timeout")]
+async fn test2_route() -> HttpResult<TestError> {
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[proof_route(get("/"), timeout_ms = 100)]
+async fn test3_route() -> HttpResult<TestError> {
+    sleep(Duration::from_millis(200)).await;
+    Ok(HttpResponse::Ok().finish())
+}
+
+#[test]
+async fn should_return_timeout_error() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::REQUEST_TIMEOUT);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "timed out");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_respond_before_timeout() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::OK);
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_default_to_gateway_timeout() {
+    let (thread, server, address) = web_server!(test3_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::GATEWAY_TIMEOUT);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "request timed out");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,88 @@
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use actix_error_proc_macros::ActixError;
+use actix_web::HttpResponse;
+use thiserror::Error;
+use tracing::subscriber::with_default;
+use tracing_subscriber::fmt;
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(log_level = "warn")]
+enum TestError {
+    #[error("The user was not found.")]
+    #[http_status(NotFound)]
+    NotFound,
+    #[error("The database failed.")]
+    #[log(level = "error")]
+    Database
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestUnloggedError {
+    #[error("The user was not found.")]
+    #[http_status(NotFound)]
+    NotFound,
+    #[error("The request timed out.")]
+    #[log(level = "info")]
+    Timeout
+}
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<u8>>>);
+
+impl Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Converts the error into a response, returning what was logged meanwhile.
+fn logged(convert: impl FnOnce() -> HttpResponse) -> String {
+    let capture = Capture::default();
+    let writer = capture.clone();
+
+    let subscriber = fmt()
+        .with_ansi(false)
+        .with_max_level(tracing::Level::TRACE)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    with_default(subscriber, || {
+        convert();
+    });
+
+    let output = capture.0.lock().unwrap().clone();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn should_log_with_enum_level() {
+    let output = logged(|| TestError::NotFound.into());
+
+    assert!(output.contains("WARN"), "{output}");
+    assert!(output.contains("HTTP error response"), "{output}");
+    assert!(output.contains("error=The user was not found."), "{output}");
+}
+
+#[test]
+fn should_log_with_variant_level() {
+    let output = logged(|| TestError::Database.into());
+
+    assert!(output.contains("ERROR"), "{output}");
+    assert!(output.contains("error=The database failed."), "{output}");
+}
+
+#[test]
+fn should_only_log_variants_with_level() {
+    assert_eq!(logged(|| TestUnloggedError::NotFound.into()), "");
+
+    let output = logged(|| TestUnloggedError::Timeout.into());
+
+    assert!(output.contains("INFO"), "{output}");
+}
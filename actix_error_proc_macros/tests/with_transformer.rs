@@ -13,11 +13,22 @@ fn transformer(mut req: HttpResponseBuilder, fmt: String) -> HttpResponse {
         .body("no")
 }
 
+fn variant_transformer(mut req: HttpResponseBuilder, fmt: String) -> HttpResponse {
+    req
+        .append_header(("variant-format", fmt))
+        .body("variant")
+}
+
 #[derive(ActixError, Error, Debug)]
 #[actix_error(transformer = "transformer")]
 enum TestError {
     #[error("test")]
-    Test
+    Test,
+
+    #[error("test2")]
+    #[http_status(BadRequest)]
+    #[http_transformer = "variant_transformer"]
+    Test2
 }
 
 #[proof_route(get("/"))]
@@ -25,6 +36,11 @@ async fn test_route() -> HttpResult<TestError> {
     Err(TestError::Test)
 }
 
+#[proof_route(get("/"))]
+async fn test2_route() -> HttpResult<TestError> {
+    Err(TestError::Test2)
+}
+
 #[test]
 async fn should_return_fmt_as_headers() {
     let (thread, server, address) = web_server!(test_route);
@@ -52,3 +68,31 @@ async fn should_return_fmt_as_headers() {
     server.stop(true).await;
     thread.join().unwrap();
 }
+
+#[test]
+async fn should_prefer_variant_transformer() {
+    let (thread, server, address) = web_server!(test2_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let header = result
+        .headers()
+        .get("variant-format")
+        .expect("Missing variant-format header.");
+
+    assert_eq!(header, "test2");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "variant");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
@@ -0,0 +1,136 @@
+use actix_error_proc_macros::{proof_route, ActixError};
+use actix_web::HttpResponse;
+use crate::shared::HttpResult;
+use thiserror::Error;
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+#[derive(ActixError, Error, Debug)]
+enum AuthError {
+    #[error("unauthorized")]
+    #[http_status(Unauthorized)]
+    #[http_header("WWW-Authenticate", "Bearer")]
+    Unauthorized
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestError {
+    #[error(transparent)]
+    #[actix_transparent]
+    Auth(AuthError),
+
+    #[error(transparent)]
+    #[actix_transparent]
+    Named { inner: AuthError }
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestDelegatedError {
+    #[error(transparent)]
+    #[actix_error(delegate_to = "AuthError")]
+    Auth(#[from] AuthError)
+}
+
+#[derive(ActixError, Error, Debug)]
+enum TestMessageError {
+    #[error(transparent)]
+    Auth(AuthError)
+}
+
+// the inner error only converts into a response, it doesn't implement `Display`.
+#[derive(Debug)]
+struct RateLimitError;
+
+impl From<RateLimitError> for HttpResponse {
+    fn from(_: RateLimitError) -> Self {
+        HttpResponse::TooManyRequests().body("slow down")
+    }
+}
+
+#[derive(ActixError, Debug)]
+#[actix_error(impl_into_string, with_display = "rate limited")]
+enum TestPlainError {
+    #[actix_transparent]
+    RateLimit(RateLimitError)
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::Auth(AuthError::Unauthorized))
+}
+
+#[proof_route(get("/"))]
+async fn test_message_route() -> HttpResult<TestMessageError> {
+    Err(TestMessageError::Auth(AuthError::Unauthorized))
+}
+
+#[test]
+async fn should_forward_inner_response() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(result.headers().get("www-authenticate").unwrap(), "Bearer");
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "unauthorized");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_forward_inner_error() {
+    let error: actix_web::Error = TestError::Named { inner: AuthError::Unauthorized }.into();
+
+    assert_eq!(error.to_string(), "unauthorized");
+    assert_eq!(error.error_response().status().as_u16(), 401);
+}
+
+#[test]
+async fn should_only_forward_message_without_attribute() {
+    let (thread, server, address) = web_server!(test_message_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(result.headers().get("www-authenticate").is_none());
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "unauthorized");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_delegate_to_inner_error() {
+    let response: HttpResponse = TestDelegatedError::from(AuthError::Unauthorized).into();
+
+    assert_eq!(response.status().as_u16(), 401);
+    assert_eq!(response.headers().get("WWW-Authenticate").unwrap(), "Bearer");
+}
+
+#[test]
+async fn should_forward_inner_body_without_display() {
+    let error: actix_web::Error = TestPlainError::RateLimit(RateLimitError).into();
+
+    assert_eq!(error.to_string(), "slow down");
+    assert_eq!(error.error_response().status().as_u16(), 429);
+    assert_eq!(String::from(TestPlainError::RateLimit(RateLimitError)), "slow down");
+}
@@ -0,0 +1,38 @@
+use actix_error_proc_macros::ActixError;
+use thiserror::Error;
+
+#[derive(ActixError, Error, Debug, PartialEq)]
+#[actix_error(impl_try_from_status)]
+enum TestError {
+    #[error("not found")]
+    #[http_status(NotFound)]
+    NotFound,
+
+    #[error("invalid {0}")]
+    #[http_status(BadRequest)]
+    Invalid(String),
+
+    #[error("also invalid")]
+    #[http_status(400)]
+    AlsoInvalid,
+
+    #[error("internal")]
+    #[actix_error(priority = 10)]
+    Internal
+}
+
+#[test]
+fn should_build_unit_variant() {
+    assert_eq!(TestError::try_from(404), Ok(TestError::NotFound));
+    assert_eq!(TestError::try_from(500), Ok(TestError::Internal));
+}
+
+#[test]
+fn should_build_tuple_variant_with_defaults() {
+    assert_eq!(TestError::try_from(400), Ok(TestError::Invalid(String::new())));
+}
+
+#[test]
+fn should_reject_unknown_status() {
+    assert_eq!(TestError::try_from(418), Err(()));
+}
@@ -0,0 +1,79 @@
+use actix_web::{HttpResponse, HttpResponseBuilder};
+use crate::shared::HttpResult;
+use thiserror::Error;
+use actix_error_proc_macros::{proof_route, ActixError};
+use reqwest::{get, StatusCode};
+use tokio::test;
+
+mod shared;
+
+fn transformer(mut req: HttpResponseBuilder, fmt: String) -> HttpResponse {
+    req
+        .append_header(("format", fmt))
+        .finish()
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(include_variant_name)]
+enum TestError {
+    #[error("The user was not found.")]
+    #[http_status(NotFound)]
+    UserNotFound
+}
+
+#[derive(ActixError, Error, Debug)]
+#[actix_error(include_variant_name, variant_case = "snake", transformer = "transformer")]
+enum SnakeError {
+    #[error("Invalid HTTP header.")]
+    #[http_status(BadRequest)]
+    InvalidHTTPHeader
+}
+
+#[proof_route(get("/"))]
+async fn test_route() -> HttpResult<TestError> {
+    Err(TestError::UserNotFound)
+}
+
+#[proof_route(get("/"))]
+async fn snake_route() -> HttpResult<SnakeError> {
+    Err(SnakeError::InvalidHTTPHeader)
+}
+
+#[test]
+async fn should_prefix_variant_name() {
+    let (thread, server, address) = web_server!(test_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::NOT_FOUND);
+
+    let text = result
+        .text()
+        .await
+        .expect("Error while reading response body.");
+
+    assert_eq!(text, "[USER_NOT_FOUND] The user was not found.");
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}
+
+#[test]
+async fn should_pass_snake_case_name_to_transformer() {
+    let (thread, server, address) = web_server!(snake_route);
+
+    let result = get(address)
+        .await
+        .expect("Error while making the request.");
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        result.headers().get("format").unwrap(),
+        "[invalid_http_header] Invalid HTTP header."
+    );
+
+    server.stop(true).await;
+    thread.join().unwrap();
+}